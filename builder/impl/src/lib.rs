@@ -0,0 +1,4337 @@
+//! Provides the [`Builder`] derive macro.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use std::collections::HashSet;
+use syn::{
+    braced, ext::IdentExt, meta::ParseNestedMeta, parenthesized, parse_macro_input, parse_quote,
+    spanned::Spanned, token, Attribute, Data, DataEnum, DeriveInput, Error, Expr, Field, Fields,
+    GenericArgument, Ident, ImplItemFn, Index, LitStr, Member, Meta, Path, PathArguments, Token,
+    Type, Visibility,
+};
+
+/// A derive macro that generates the boilerplate code involved in
+/// implementing the builder pattern in Rust. Builders are a mechanism
+/// for instantiating structs, especially structs with many fields,
+/// and especially if many of those fields are optional or the set of
+/// fields may need to grow backward compatibly over time.
+///
+/// # Example
+///
+/// ```
+/// use derive_builder::Builder;
+///
+/// #[derive(Builder)]
+/// pub struct Command {
+///     executable: String,
+///     #[builder(each = "arg")]
+///     args: Vec<String>,
+///     current_dir: Option<String>,
+/// }
+///
+/// let command = Command::builder()
+///     .executable("cargo".to_owned())
+///     .arg("build".to_owned())
+///     .arg("--release".to_owned())
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(command.executable, "cargo");
+/// assert_eq!(command.args, vec!["build", "--release"]);
+/// assert_eq!(command.current_dir, None);
+/// ```
+///
+/// # Generated code
+///
+/// More generally, let's talk about what code is generated by
+/// deriving [`Builder`] on a struct called `Example`.
+///
+/// ## Methods on the struct on which `Builder` is derived
+///
+/// The `builder() -> ExampleBuilder` method is generated on the
+/// `Example` struct. `ExampleBuilder` also implements
+/// [`Default`](https://doc.rust-lang.org/std/default/trait.Default.html),
+/// which returns the same builder as `Example::builder()`.
+///
+/// `Example` also implements the `derive_builder::Buildable` trait, whose
+/// `builder()` function returns the same builder, so that generic code can
+/// construct any type that derives [`Builder`]. The only exception is a
+/// builder made less visible than `Example` with the `builder(vis = "...")`
+/// attribute described below, e.g. `pub(crate)` for a `pub` struct, as the
+/// trait would expose the builder wherever `Example` is visible.
+///
+/// If `Example` is a struct, then `ExampleBuilder` also implements
+/// `From<Example>`, which returns a builder pre-populated with the values of
+/// the given `Example`, so that a modified copy can be built from it. If
+/// `Example` implements [`Clone`](https://doc.rust-lang.org/std/clone/trait.Clone.html),
+/// then the `to_builder(&Example) -> ExampleBuilder` method is generated on
+/// `Example` as a shorthand. Computed and skipped fields, described below,
+/// are not carried over, as they are filled in again by `build()`.
+///
+/// If `Example` is a struct marked with the `builder(constructor)` attribute,
+/// then the `new(...) -> Example` function is generated on `Example` as
+/// well. It takes one parameter for each required field, in the order of
+/// the fields, and sets the other fields as if their setters were not
+/// called. Since `new()` cannot fail, this attribute cannot be used together
+/// with the `build_fn(error = "...")`, `build_fn(validate = "...")` or
+/// `build_fn(wrap = "...")` attributes, nor with fields marked with
+/// `builder(env = "...")` or `builder(sub_builder)`.
+///
+/// ```
+/// use derive_builder::Builder;
+///
+/// #[derive(Builder)]
+/// #[builder(constructor)]
+/// pub struct Command {
+///     executable: String,
+///     current_dir: Option<String>,
+/// }
+///
+/// let command = Command::new("cargo".to_owned());
+/// assert_eq!(command.current_dir, None);
+/// ```
+///
+/// If `Example` is a struct marked with the `builder(remote = "Remote")`
+/// attribute, where `Remote` is the path of a struct that has the same
+/// fields as `Example`, but is defined in another crate, then
+/// `ExampleBuilder` builds a `Remote` instead of an `Example`. `Example`
+/// only serves as a local description of the fields of `Remote`, which are
+/// set with struct expression syntax, so they must all be visible. A field
+/// whose type differs from the one of the corresponding field of `Remote` is
+/// reported as an error on its type. Since methods cannot be added to
+/// `Remote`, the conversions from `Remote` to `ExampleBuilder` are not
+/// generated, and neither is the `derive_builder::Buildable` implementation,
+/// as `ExampleBuilder` does not build an `Example`. This attribute cannot be
+/// used together with the `builder(constructor)` attribute.
+///
+/// ```
+/// use derive_builder::Builder;
+///
+/// mod other {
+///     pub struct Config {
+///         pub host: String,
+///         pub port: u16,
+///     }
+/// }
+///
+/// #[derive(Builder)]
+/// #[builder(remote = "other::Config")]
+/// pub struct ConfigDef {
+///     host: String,
+///     #[builder(default = "80")]
+///     port: u16,
+/// }
+///
+/// let config: other::Config = ConfigDef::builder()
+///     .host("localhost".to_owned())
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(config.port, 80);
+/// ```
+///
+/// ## The `ExampleBuilder` struct
+///
+/// The `ExampleBuilder` struct has setter methods for each of
+/// the fields present in the `Example` struct, generated according
+/// to the following rules:
+/// * if we have a field `field_name: Option<T>`, then the method
+///   `field_name(&mut ExampleBuilder, field_name: T) -> &mut ExampleBuilder`
+///   is generated. If this method is not called, the
+///   [`Option`](https://doc.rust-lang.org/std/option/enum.Option.html)
+///   remains [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None);
+/// * if we have a field `field_name: Vec<T>` marked with the
+///   `builder(each = "setter_name")` attribute, then the method
+///   `setter_name(&mut ExampleBuilder, setter_name: T) -> &mut ExampleBuilder`
+///   is generated. It can be used to append values to the
+///   [`Vec`](https://doc.rust-lang.org/std/vec/struct.Vec.html),
+///   one a time. If `setter_name` does not conflict with `field_name`, then the method
+///   `field_name(&mut ExampleBuilder, field_name: Vec<T>) -> &mut ExampleBuilder`
+///   is also generated. It can be used to set all the values in the
+///   [`Vec`](https://doc.rust-lang.org/std/vec/struct.Vec.html), all at once,
+///   overwriting any existing values. If neither of these two methods are called, the
+///   [`Vec`](https://doc.rust-lang.org/std/vec/struct.Vec.html) stays empty;
+/// * otherwise, if we have a field `field_name: T`, then the method
+///   `field_name(&mut ExampleBuilder, field_name: T) -> &mut ExampleBuilder`
+///   is generated. If this method is not called, an error will be returned
+///   from the `build()` method, unless the field is marked with the
+///   `builder(default)` attribute, in which case the field is set to
+///   [`Default::default()`](https://doc.rust-lang.org/std/default/trait.Default.html#tymethod.default),
+///   or with the `builder(default = "expression")` attribute, in which case
+///   the field is set to the result of `expression`. The latter form can also
+///   be used on `Option<T>` fields, where `expression` must be an `Option<T>`.
+///
+/// The `builder(each = "setter_name")` attribute can also be used on a
+/// `field_name: HashMap<K, V>` or a `field_name: BTreeMap<K, V>` field, in
+/// which case the method
+/// `setter_name(&mut ExampleBuilder, key: K, value: V) -> &mut ExampleBuilder`
+/// inserts one entry at a time into the map instead. The map must be named
+/// by its path in [`std::collections`](https://doc.rust-lang.org/std/collections/index.html).
+///
+/// If the generated `field_name` method is undesired next to `setter_name`,
+/// it can be omitted by also marking the field with the
+/// `builder(setter(append_only))` attribute.
+///
+/// For both kinds of fields marked with `builder(each = "setter_name")`, the
+/// method `extend_field_name(&mut ExampleBuilder, field_name: impl IntoIterator<Item = T>) -> &mut ExampleBuilder`
+/// is generated as well, where `T` is the element type of the
+/// [`Vec`](https://doc.rust-lang.org/std/vec/struct.Vec.html), or the `(K, V)`
+/// pair type of the map. It can be used to add many values at once, keeping
+/// the existing ones.
+///
+/// If a field that is not marked with `builder(each = "...")` is marked with
+/// the `builder(env = "VARIABLE")` attribute, then `build()` falls back to
+/// parsing the `VARIABLE` environment variable with
+/// [`FromStr`](https://doc.rust-lang.org/std/str/trait.FromStr.html) when
+/// the setter for the field was not called. The rules above only apply if
+/// the environment variable is not set either. If it is set but cannot be
+/// parsed, then `build()` returns an error.
+///
+/// If `Example` is marked with the `builder(default_from = "TEMPLATE")`
+/// attribute, where `TEMPLATE` is a constant or a static of type `Example`,
+/// then the fields with a single setter that are not marked with
+/// `builder(default)`, `builder(default = "expression")` or
+/// `builder(required)` are no longer required. If their setter is not
+/// called, they are set to a clone of the corresponding field of `TEMPLATE`,
+/// or, in the const builders described below, to the field itself, in which
+/// case `TEMPLATE` must be a constant.
+///
+/// If a field is marked with the `builder(setter(into))` attribute, then
+/// its setters accept any value that can be converted into the parameter
+/// type described above. For example, the setter for a `field_name: String`
+/// field becomes `field_name(&mut ExampleBuilder, field_name: impl Into<String>) -> &mut ExampleBuilder`,
+/// so it can be called with a `&str`.
+///
+/// A `field_name: Option<T>` field that is marked with the
+/// `builder(required)` attribute follows the last rule instead, i.e. its
+/// setter takes an `Option<T>` that must be provided, possibly as
+/// [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None),
+/// before calling `build()`.
+///
+/// The behavior described above for `Option<T>` fields can also be requested
+/// explicitly with the `builder(setter(strip_option))` attribute, in which
+/// case a compile error is reported if the field is not a standard
+/// [`Option`](https://doc.rust-lang.org/std/option/enum.Option.html).
+///
+/// For a `field_name: Option<T>` field that is not marked with
+/// `builder(required)`, the setter
+/// `maybe_field_name(&mut ExampleBuilder, field_name: Option<T>) -> &mut ExampleBuilder`
+/// is generated as well. It sets the field to the given `Option<T>` as is,
+/// which avoids an `if let` when forwarding a value that is already optional.
+///
+/// If a field that is not marked with `builder(each = "...")` is also marked
+/// with the `builder(setter(try_into))` attribute, then the fallible setter
+/// `try_field_name<V: TryInto<T>>(&mut ExampleBuilder, field_name: V) -> Result<&mut ExampleBuilder, V::Error>`
+/// is generated as well, where `T` is the parameter type described above.
+/// For a field marked with `builder(each = "setter_name")`, the fallible
+/// setter is `try_setter_name` instead. It converts the appended value, or
+/// only the value of the inserted entry for maps, and leaves the field
+/// unchanged if the conversion fails.
+///
+/// The documentation comments of each field are copied onto the setters of
+/// the field, before the generated documentation, so that they are shown
+/// when hovering over a setter in an IDE.
+///
+/// For each field `field_name` that is not computed or skipped, the
+/// `get_field_name(&ExampleBuilder)` method is generated as well. It returns
+/// `Option<&T>`, which is [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None)
+/// until the setter for the field is called, or a slice or a reference to the
+/// map for fields marked with `builder(each = "...")`. It can be used to check
+/// which values were supplied before calling `build()`.
+///
+/// Likewise, the `clear_field_name(&mut ExampleBuilder) -> &mut ExampleBuilder`
+/// method is generated for each such field. It reverts the field to the state
+/// it had before any of its setters was called, e.g. it empties the
+/// collection of a field marked with `builder(each = "...")`, which allows a
+/// builder to be reused as a template with some fields swapped between
+/// builds. In typestate builders, described below, it is not generated for
+/// the required fields.
+///
+/// If `Example` is a tuple struct, the fields are named after their
+/// position, so the setter for the first field is `field_0`, the setter
+/// for the second field is `field_1` and so on.
+///
+/// If `Example` is marked with the `builder(setter(owned))` attribute, then
+/// all the setters take and return `ExampleBuilder` by value instead, e.g.
+/// `field_name(ExampleBuilder, field_name: T) -> ExampleBuilder`, so that a
+/// fully configured builder can be stored with `let builder = Example::builder().field_name(value);`.
+/// The default behavior can also be requested explicitly with the
+/// `builder(setter(mutable))` attribute.
+///
+/// If `Example` is marked with the `builder(setter(prefix = "with_"))`
+/// attribute, then the setters named after a field are prefixed accordingly,
+/// so `field_name` becomes `with_field_name`, `try_field_name` becomes
+/// `try_with_field_name` and `maybe_field_name` becomes `maybe_with_field_name`. Setters named with `builder(each = "...")` are
+/// left unchanged.
+///
+/// If `Example` is marked with the `builder(rename_all = "camelCase")`
+/// attribute, then the methods named after a field follow the given naming
+/// convention instead, so `field_name` becomes `fieldName`,
+/// `maybe_field_name` becomes `maybeFieldName` and `get_field_name` becomes
+/// `getFieldName`. The supported conventions are `lowercase`, `UPPERCASE`,
+/// `PascalCase`, `camelCase`, `snake_case` and `SCREAMING_SNAKE_CASE`. The
+/// convention applies to the whole name, including the prefix, so with
+/// `setter(prefix = "with_")` the setter is called `withFieldName`. Names
+/// given explicitly, like those of `builder(each = "...")`, are left
+/// unchanged, but the `try_` setters derived from them are converted.
+///
+/// The setter of a single field can be renamed with the
+/// `builder(setter(name = "setter_name"))` attribute on the field, which
+/// takes precedence over the prefix. This is mostly useful for fields named
+/// with raw identifiers, e.g. to call the setter of an `r#type` field
+/// `type_` instead of `r#type`:
+///
+/// ```
+/// use derive_builder::Builder;
+///
+/// #[derive(Builder)]
+/// pub struct Token {
+///     #[builder(setter(name = "type_"))]
+///     r#type: String,
+/// }
+///
+/// let token = Token::builder().type_("ident".to_owned()).build().unwrap();
+/// assert_eq!(token.r#type, "ident");
+/// ```
+///
+/// A `build(&mut ExampleBuilder) -> Result<Example, ExampleBuilderErr>` is
+/// also generated. As stated above, it will return an error if any
+/// required setter was not called. If no field of `Example` is required,
+/// then there is nothing that can go wrong, so the method is generated as
+/// `build(&mut ExampleBuilder) -> Example` instead, unless one of the
+/// `build_fn(error = "...")` or `build_fn(validate = "...")` attributes
+/// described below is present.
+///
+/// The name of the `build()` method can be changed with the
+/// `builder(build_fn(name = "method_name"))` attribute on `Example`, which
+/// is useful if `ExampleBuilder` needs a user-defined `build()` method.
+///
+/// By default, `build()` moves the values out of `ExampleBuilder`, leaving it
+/// as if no setter was called. If `Example` is marked with the
+/// `builder(build_fn(by_ref))` attribute, then the method is generated as
+/// `build(&ExampleBuilder)` instead and clones the values, so that the same
+/// builder can be used as a template for multiple instances. This requires
+/// all the fields to implement [`Clone`](https://doc.rust-lang.org/std/clone/trait.Clone.html).
+///
+/// The built value can be wrapped in a smart pointer with the
+/// `builder(build_fn(wrap = "Arc"))` attribute on `Example`, in which case
+/// `build()` returns a `Result<Arc<Example>, ExampleBuilderErr>`, or
+/// `Arc<Example>` if it cannot fail. The path is resolved where `Example` is
+/// defined and any type with a `new(Example)` function can be used, such as
+/// `Arc`, `Rc` or `Box`. Nested builders are not affected and still build
+/// the unwrapped value.
+///
+/// Unless `Example` is an enum or a typestate builder, both described below,
+/// an `apply(&mut ExampleBuilder, target: &mut Example)` method is generated
+/// as well. Instead of building a new `Example`, it overwrites the fields of
+/// `target` whose setters were called, which is useful for partial updates
+/// of an existing value. Like `build()`, it moves the values out of the
+/// builder, or clones them with `builder(build_fn(by_ref))`, so it can be
+/// called at the end of a chain of setters. The fields marked with
+/// `builder(each = "...")` are only overwritten if values were added to
+/// them, the nested builders described below are applied recursively, and
+/// the computed fields and the fields marked with `builder(field(...))` are
+/// left unchanged. Neither the `finalize` hook nor the validation hook is
+/// called. The method is not generated if a setter or a mutator of the
+/// builder is already named `apply`.
+///
+/// A validation hook can be declared on `Example` with the
+/// `builder(build_fn(validate = "path::to::hook"))` attribute. The hook is a
+/// function with the signature `fn(&Example) -> Result<(), E>` that `build()`
+/// calls on the assembled value. If the hook returns an error, then `build()`
+/// returns that error, converted with
+/// [`From`](https://doc.rust-lang.org/std/convert/trait.From.html). For this
+/// purpose, `ExampleBuilderErr` implements `From<String>` when a validation
+/// hook is declared.
+///
+/// The name of the builder struct can be changed with the
+/// `builder(name = "ExampleParams")` attribute on `Example`, which is useful
+/// if `ExampleBuilder` collides with an existing type. The builder error
+/// type is then named `ExampleParamsErr`. For enums, this only renames the
+/// builder used to choose a variant.
+///
+/// Extra traits can be derived for the builder struct with the
+/// `builder(derive(Trait, ...))` attribute on `Example`. For example,
+/// `builder(derive(Clone, Debug))` makes it possible to clone a
+/// partially-filled `ExampleBuilder`. `Default` cannot be listed there,
+/// since `ExampleBuilder` already implements it.
+///
+/// If `Example` is marked with the `builder(serde)` attribute, then
+/// `ExampleBuilder` derives `serde::Deserialize`, with every field being
+/// optional, so that a partial configuration can be deserialized, completed
+/// with the setters and then checked by `build()`. For enums, the variant
+/// builders derive it instead. This requires the crate that derives
+/// [`Builder`] to depend on [`serde`](https://serde.rs) with the `derive`
+/// feature enabled.
+///
+/// Hand-written methods can be added to `ExampleBuilder` with the
+/// `builder(mutators { ... })` attribute on `Example`, which contains the
+/// methods as they would be written in an `impl ExampleBuilder` block. They
+/// have access to the fields of the builder, which are named after the
+/// fields of `Example`. A field is stored as an `Option<T>` in the builder,
+/// except for fields marked with `builder(each = "...")`, which are stored
+/// as is.
+///
+/// ```
+/// use derive_builder::Builder;
+///
+/// #[derive(Builder)]
+/// #[builder(mutators {
+///     pub fn header(&mut self, name: &str, value: &str) -> &mut Self {
+///         self.headers.push(format!("{name}: {value}"));
+///         self
+///     }
+/// })]
+/// pub struct Request {
+///     url: String,
+///     #[builder(each = "raw_header")]
+///     headers: Vec<String>,
+/// }
+///
+/// let request = Request::builder()
+///     .url("https://example.com".to_owned())
+///     .header("Accept", "*/*")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(request.headers, vec!["Accept: */*"]);
+/// ```
+///
+/// ## Computed fields
+///
+/// A field marked with the `builder(computed)` attribute gets no setter
+/// and is initialized with [`Default::default()`](https://doc.rust-lang.org/std/default/trait.Default.html#tymethod.default),
+/// or with the expression given in `builder(computed = "expression")`.
+/// Such fields must be filled in by a hook declared on the struct with the
+/// `builder(finalize = "path::to::hook")` attribute. The hook is a function
+/// with the signature `fn(&mut Example)` that `build()` calls on the
+/// assembled value before returning it, and before any validation hook:
+///
+/// ```
+/// use derive_builder::Builder;
+///
+/// #[derive(Builder)]
+/// #[builder(finalize = "compute_len")]
+/// pub struct Line {
+///     text: String,
+///     #[builder(computed)]
+///     len: usize,
+/// }
+///
+/// fn compute_len(line: &mut Line) {
+///     line.len = line.text.len();
+/// }
+///
+/// let line = Line::builder().text("four".to_owned()).build().unwrap();
+/// assert_eq!(line.len, 4);
+/// ```
+///
+/// A field that is only used internally, such as a cache, can be marked with
+/// the `builder(skip)` attribute instead, or with `builder(skip = "expression")`.
+/// Such a field gets no setter either and is initialized the same way, but
+/// no `finalize` hook is required:
+///
+/// ```
+/// use derive_builder::Builder;
+/// use std::collections::HashMap;
+///
+/// #[derive(Builder)]
+/// pub struct Resolver {
+///     server: String,
+///     #[builder(skip)]
+///     cache: HashMap<String, String>,
+///     #[builder(skip = "64")]
+///     cache_capacity: usize,
+/// }
+///
+/// let resolver = Resolver::builder().server("1.1.1.1".to_owned()).build().unwrap();
+/// assert!(resolver.cache.is_empty());
+/// assert_eq!(resolver.cache_capacity, 64);
+/// ```
+///
+/// Fields of type [`PhantomData<T>`](https://doc.rust-lang.org/std/marker/struct.PhantomData.html)
+/// that are not marked with any `builder` attribute are skipped as well, so
+/// they get no setter and are always set to `PhantomData`.
+///
+/// ## Nested builders
+///
+/// A field whose type also derives [`Builder`] can be marked with the
+/// `builder(sub_builder)` attribute. Instead of a setter, the method
+/// `field_name_builder(&mut ExampleBuilder) -> &mut FieldTypeBuilder` is
+/// generated, which gives access to the builder of the field, and `build()`
+/// builds the field from it. If that fails, then `build()` returns an error
+/// as well, whose [`source()`](https://doc.rust-lang.org/std/error/trait.Error.html#method.source)
+/// is the error returned by the builder of the field. If that builder uses a
+/// custom error type, then it must implement
+/// [`Error`](https://doc.rust-lang.org/std/error/trait.Error.html), `Send`
+/// and `Sync`. The builder of the field is expected to be named after the
+/// type of the field, as described above.
+///
+/// ```
+/// use derive_builder::Builder;
+///
+/// #[derive(Builder)]
+/// pub struct Server {
+///     #[builder(sub_builder)]
+///     listener: Listener,
+/// }
+///
+/// #[derive(Builder)]
+/// pub struct Listener {
+///     port: u16,
+/// }
+///
+/// let mut builder = Server::builder();
+/// builder.listener_builder().port(8080);
+///
+/// let server = builder.build().unwrap();
+/// assert_eq!(server.listener.port, 8080);
+/// ```
+///
+/// ## Intermediate field types
+///
+/// A field can be accumulated into a different type than its own with the
+/// `builder(field(ty = "IntermediateType", build = "expression"))` attribute.
+/// `ExampleBuilder` then stores an `IntermediateType`, which starts out as
+/// its default value and is what the setter and the getter of the field
+/// work with. When `build()` is called, the expression converts it into the
+/// type of the field. In the expression, `self` is a builder that owns the
+/// intermediate value of the field.
+///
+/// ```
+/// use derive_builder::Builder;
+///
+/// #[derive(Builder)]
+/// #[builder(mutators {
+///     pub fn item(&mut self, item: u32) -> &mut Self {
+///         self.items.push(item);
+///         self
+///     }
+/// })]
+/// pub struct Batch {
+///     #[builder(field(ty = "Vec<u32>", build = "self.items.into_boxed_slice()"))]
+///     items: Box<[u32]>,
+/// }
+///
+/// let batch = Batch::builder().item(1).item(2).build();
+/// assert_eq!(*batch.items, [1, 2]);
+/// ```
+///
+/// ## Typestate builders
+///
+/// If `Example` is marked with the `builder(typestate)` attribute, then
+/// calling `build()` before all the required setters were called is a
+/// compile error instead of a runtime error. `ExampleBuilder` gets one type
+/// parameter for each required field, which records whether the setter for
+/// that field was called. Because calling a setter changes the type of the
+/// builder, all the setters take and return the builder by value, and
+/// `build(ExampleBuilder<...>) -> Example` returns `Example` directly.
+/// The getters of the required fields return `&T` instead of `Option<&T>`
+/// and are only available once the setter for the field was called.
+/// No `ExampleBuilderErr` struct is generated in this mode.
+///
+/// ```
+/// use derive_builder::Builder;
+///
+/// #[derive(Builder)]
+/// #[builder(typestate)]
+/// pub struct Command {
+///     executable: String,
+///     current_dir: Option<String>,
+/// }
+///
+/// let command = Command::builder()
+///     .current_dir("..".to_owned())
+///     .executable("cargo".to_owned())
+///     .build();
+///
+/// assert_eq!(command.executable, "cargo");
+/// ```
+///
+/// ```compile_fail
+/// use derive_builder::Builder;
+///
+/// #[derive(Builder)]
+/// #[builder(typestate)]
+/// pub struct Command {
+///     executable: String,
+///     current_dir: Option<String>,
+/// }
+///
+/// let command = Command::builder().current_dir("..".to_owned()).build();
+/// ```
+///
+/// ## Enums
+///
+/// If `Example` is an enum, then `ExampleBuilder` has no setters. Instead,
+/// it has one method for each variant, named after the variant in
+/// `snake_case`, that returns a builder for that variant. For a variant
+/// called `Variant`, the returned `ExampleVariantBuilder` has setters for
+/// the fields of `Variant`, following the rules above, and a `build()`
+/// method that returns a `Result<Example, ExampleBuilderErr>`, or `Example`
+/// itself if `Variant` has no required fields. Like `ExampleBuilder`, each
+/// variant builder implements `Default`:
+///
+/// ```
+/// use derive_builder::Builder;
+///
+/// #[derive(Builder, Debug, PartialEq)]
+/// pub enum Endpoint {
+///     Tcp { host: String, port: u16 },
+///     Stdio,
+/// }
+///
+/// let endpoint = Endpoint::builder()
+///     .tcp()
+///     .host("localhost".to_owned())
+///     .port(8080)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(endpoint, Endpoint::Tcp { host: "localhost".to_owned(), port: 8080 });
+/// assert_eq!(Endpoint::builder().stdio().build(), Endpoint::Stdio);
+/// ```
+///
+/// ## The `ExampleBuilderErr` struct
+///
+/// The `ExampleBuilderErr` struct has a `msg(&ExampleBuilderErr) -> &str`
+/// method that can help in debugging what went wrong when building the
+/// `Example` struct. The `ExampleBuilderErr` struct also implements
+/// the [`Error`](https://doc.rust-lang.org/std/error/trait.Error.html) trait.
+///
+/// The `field(&ExampleBuilderErr) -> Option<ExampleBuilderField>` method
+/// returns which field caused the error, e.g. the required field whose
+/// setter was not called. `ExampleBuilderField` is a generated enum with one
+/// variant for each field of `Example`, named after the field in
+/// `UpperCamelCase`. For enums, the variants are prefixed with the name of
+/// the variant that the field belongs to.
+///
+/// `build()` checks all the required fields before building `Example`, so if
+/// the setters of several of them were not called, the error reports all of
+/// them at once. The `missing_fields(&ExampleBuilderErr) -> &[ExampleBuilderField]`
+/// method returns these fields, and `field()` returns the first of them.
+///
+/// ```
+/// use derive_builder::Builder;
+///
+/// #[derive(Builder, Debug)]
+/// pub struct Command {
+///     executable: String,
+///     args: Vec<String>,
+///     current_dir: Option<String>,
+/// }
+///
+/// let err = Command::builder().build().unwrap_err();
+/// assert_eq!(err.msg(), "missing fields: `executable`, `args`");
+/// assert_eq!(err.field(), Some(CommandBuilderField::Executable));
+/// assert_eq!(
+///     err.missing_fields(),
+///     [CommandBuilderField::Executable, CommandBuilderField::Args],
+/// );
+/// ```
+///
+/// If `Example` is marked with the `builder(build_fn(error = "MyError"))`
+/// attribute, then `build()` returns a `Result<Example, MyError>` instead,
+/// and `MyError` must implement `From<ExampleBuilderErr>`.
+///
+/// # `no_std` support
+///
+/// If `Example` is marked with the `builder(no_std)` attribute, then the
+/// generated code only refers to the `core` and `alloc` crates instead of
+/// `std`, so the crate that derives [`Builder`] must declare
+/// `extern crate alloc;`. The `Option`, `Vec` and `BTreeMap` fields may then
+/// also be spelled as `core::option::Option`, `alloc::vec::Vec` and
+/// `alloc::collections::BTreeMap`. The `builder(env = "...")` attribute
+/// cannot be used in this mode, because reading environment variables
+/// requires `std`.
+///
+/// ```
+/// # extern crate alloc;
+/// use alloc::string::String;
+/// use derive_builder::Builder;
+///
+/// #[derive(Builder)]
+/// #[builder(no_std)]
+/// pub struct Device {
+///     name: String,
+///     address: u16,
+/// }
+/// ```
+///
+/// # Const builders
+///
+/// If `Example` is marked with the `builder(const)` attribute, then
+/// `builder()`, the setters and `build()` are `const fn`, so `Example` can
+/// be built in a constant. `build()` then returns `Example` directly and
+/// panics if the setter of a required field was not called, which is a
+/// compile error when it is evaluated in a constant. The types of the
+/// fields must not need dropping, and in typestate builders the types of
+/// the required fields must be `Copy`. The `each`, `env`, `sub_builder`, `field`, `into` and
+/// `try_into` field attributes cannot be used in this mode, `default`,
+/// `skip` and `computed` require an expression, and the `error` and
+/// `validate` keys of `build_fn` as well as `by_ref` are not supported.
+///
+/// ```
+/// use derive_builder::Builder;
+///
+/// #[derive(Builder)]
+/// #[builder(const)]
+/// pub struct Limits {
+///     max_connections: u32,
+///     #[builder(default = "30")]
+///     timeout: u32,
+/// }
+///
+/// const LIMITS: Limits = Limits::builder().max_connections(64).build();
+///
+/// assert_eq!(LIMITS.timeout, 30);
+/// ```
+///
+/// # Notes on visibility
+///
+/// By default, the following items have the same visibility as the struct
+/// on which [`Builder`] is derived:
+/// * the builder struct itself, as well as the per-variant builder structs for enums;
+/// * the builder setter methods;
+/// * the `build` method on the builder;
+/// * the builder error type and the enum of its fields;
+/// * the `msg`, `field` and `missing_fields` methods on the builder error type;
+/// * the `builder` method on the struct on which `Builder` is derived.
+///
+/// So, this will compile:
+///
+/// ```
+/// mod process {
+///     #[derive(derive_builder::Builder, Debug)]
+///     pub struct Command {
+///         executable: String,
+///         #[builder(each = "arg")]
+///         args: Vec<String>,
+///         current_dir: Option<String>,
+///     }
+/// }
+///
+/// let _ = process::Command::builder()
+///     .executable("cargo".to_owned())
+///     .build()
+///     .unwrap();
+/// ```
+///
+/// but this will not, because the generated struct
+/// `CommandBuilder` is private:
+///
+/// ```compile_fail
+/// mod process {
+///     #[derive(derive_builder::Builder, Debug)]
+///     struct Command {
+///         _executable: String,
+///         #[builder(each = "arg")]
+///         _args: Vec<String>,
+///         _current_dir: Option<String>,
+///     }
+/// }
+///
+/// use process::CommandBuilder;
+/// ```
+///
+/// The visibility of all these items can be set explicitly with the
+/// `builder(vis = "...")` attribute, e.g. `builder(vis = "pub(crate)")`
+/// to keep the builder of a public struct internal to the crate. An empty
+/// string makes the items private.
+#[proc_macro_derive(Builder, attributes(builder))]
+pub fn derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match convert_input_to_output(&input) {
+        Ok(stream) => stream,
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn convert_input_to_output(input: &DeriveInput) -> Result<TokenStream, Error> {
+    check_input_is_not_generic(input)?;
+
+    let struct_attrs = get_struct_attrs(input)?;
+
+    let vis = struct_attrs.vis.as_ref().unwrap_or(&input.vis);
+    let caller_ty = &input.ident;
+    let core = struct_attrs.core_path();
+    let constness = struct_attrs.constness();
+
+    let builder_ty = struct_attrs
+        .name
+        .clone()
+        .unwrap_or_else(|| format_ident!("{}Builder", caller_ty));
+    let builder_err_ty = format_ident!("{}Err", builder_ty);
+    let builder_err_field_ty = format_ident!("{}Field", builder_ty);
+
+    let (builder_items, builder_ty_init, builder_err_field_variants) = match &input.data {
+        Data::Struct(data) => {
+            let fields_data = get_fields_data(&data.fields, &struct_attrs)?;
+            let path = Path::from(caller_ty.clone());
+            let target_ty = struct_attrs
+                .remote
+                .as_ref()
+                .map_or_else(|| path.clone(), |remote| remote.ty.clone());
+
+            let target = BuilderTarget {
+                vis,
+                caller_ty: &target_ty,
+                path,
+                builder_ty: builder_ty.clone(),
+                builder_err_ty: &builder_err_ty,
+                builder_err_field_ty: &builder_err_field_ty,
+                err_field_variant_idents: get_err_field_variant_idents(
+                    &fields_data,
+                    None,
+                    &mut HashSet::new(),
+                ),
+                fields_data,
+                struct_attrs: &struct_attrs,
+            };
+
+            let (items, init) = get_builder_items(&target);
+            let conversion_items = get_conversion_items(&target);
+            let apply_items = get_apply_items(&target);
+            let constructor_items = get_constructor_items(&target);
+            let remote_items = get_remote_items(&target, caller_ty);
+
+            (
+                quote! {
+                    #items #conversion_items #apply_items #constructor_items #remote_items
+                },
+                init,
+                target.err_field_variants(),
+            )
+        }
+        Data::Enum(data) => get_enum_builder_items(
+            data,
+            vis,
+            caller_ty,
+            &builder_ty,
+            &builder_err_ty,
+            &builder_err_field_ty,
+            &struct_attrs,
+        )?,
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "expected struct or enum, found `union`",
+            ))
+        }
+    };
+
+    let builder_err_ty_items = if struct_attrs.typestate.is_some() {
+        None
+    } else {
+        Some(get_builder_err_ty_items(
+            vis,
+            &builder_ty,
+            &builder_err_ty,
+            &builder_err_field_ty,
+            &builder_err_field_variants,
+            &struct_attrs,
+        ))
+    };
+
+    let builder_method_doc_string = format!(
+        "Returns a builder type used to construct an instance of `{}`.",
+        struct_attrs.remote.as_ref().map_or_else(
+            || caller_ty.unraw().to_string(),
+            |remote| get_path_name(&remote.ty)
+        )
+    );
+
+    // Implementing the trait for a builder with a narrower visibility would
+    // make the builder reachable wherever `caller_ty` is, and a builder of a
+    // remote type does not construct `caller_ty` at all.
+    let buildable_impl = if is_at_least_as_visible(vis, &input.vis) && struct_attrs.remote.is_none()
+    {
+        Some(quote! {
+            impl ::derive_builder::Buildable for #caller_ty {
+                type Builder = #builder_ty;
+
+                fn builder() -> #builder_ty {
+                    #caller_ty::builder()
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    let output = quote! {
+        #builder_err_ty_items
+
+        #builder_items
+
+        impl #caller_ty {
+            #[doc = #builder_method_doc_string]
+            #[must_use]
+            #vis #constness fn builder() -> #builder_ty {
+                #builder_ty_init
+            }
+        }
+
+        impl #core::default::Default for #builder_ty {
+            fn default() -> #builder_ty {
+                #caller_ty::builder()
+            }
+        }
+
+        #buildable_impl
+    }
+    .into();
+
+    Ok(output)
+}
+
+fn get_builder_err_ty_items(
+    vis: &Visibility,
+    builder_ty: &Ident,
+    builder_err_ty: &Ident,
+    builder_err_field_ty: &Ident,
+    builder_err_field_variants: &[TokenStream2],
+    struct_attrs: &StructAttrs,
+) -> impl ToTokens {
+    let core = struct_attrs.core_path();
+    let alloc = struct_attrs.alloc_path();
+    let build_fn = struct_attrs.build_fn_ident();
+
+    let builder_err_ty_doc_string =
+        format!("Error type returned by the `{build_fn}()` method on `{builder_ty}`.");
+    let builder_err_field_ty_doc_string =
+        format!("Identifies the field that caused a `{builder_err_ty}`.");
+
+    let from_string_impl = struct_attrs.build_fn.validate.as_ref().map(|_| {
+        quote! {
+            impl #core::convert::From<#alloc::string::String> for #builder_err_ty {
+                fn from(msg: #alloc::string::String) -> #builder_err_ty {
+                    #builder_err_ty{
+                        msg,
+                        field: #core::option::Option::None,
+                        missing_fields: #alloc::vec::Vec::new(),
+                        source: #core::option::Option::None,
+                    }
+                }
+            }
+        }
+    });
+
+    quote! {
+        #[doc = #builder_err_ty_doc_string]
+        #[derive(#core::fmt::Debug)]
+        #vis struct #builder_err_ty {
+            msg: #alloc::string::String,
+            field: #core::option::Option<#builder_err_field_ty>,
+            missing_fields: #alloc::vec::Vec<#builder_err_field_ty>,
+            source: #core::option::Option<
+                #alloc::boxed::Box<dyn #core::error::Error + #core::marker::Send + #core::marker::Sync>,
+            >,
+        }
+
+        impl #builder_err_ty {
+            fn new(field: #builder_err_field_ty, msg: &str) -> #builder_err_ty {
+                #builder_err_ty{
+                    msg: #alloc::string::String::from(msg),
+                    field: #core::option::Option::Some(field),
+                    missing_fields: #alloc::vec::Vec::new(),
+                    source: #core::option::Option::None,
+                }
+            }
+
+            fn nested<E>(field: #builder_err_field_ty, msg_prefix: &str, err: E) -> #builder_err_ty
+            where
+                E: #core::error::Error + #core::marker::Send + #core::marker::Sync + 'static,
+            {
+                #builder_err_ty{
+                    msg: #alloc::format!("{}{}", msg_prefix, err),
+                    field: #core::option::Option::Some(field),
+                    missing_fields: #alloc::vec::Vec::new(),
+                    source: #core::option::Option::Some(#alloc::boxed::Box::new(err)),
+                }
+            }
+
+            fn missing(fields: &[(#builder_err_field_ty, &str)]) -> #builder_err_ty {
+                let msg = if let [(_, name)] = fields {
+                    #alloc::format!("field `{}` was not set", name)
+                } else {
+                    let names = fields
+                        .iter()
+                        .map(|(_, name)| #alloc::format!("`{}`", name))
+                        .collect::<#alloc::vec::Vec<_>>();
+
+                    #alloc::format!("missing fields: {}", names.join(", "))
+                };
+
+                #builder_err_ty{
+                    msg,
+                    field: fields.first().map(|(field, _)| *field),
+                    missing_fields: fields.iter().map(|(field, _)| *field).collect(),
+                    source: #core::option::Option::None,
+                }
+            }
+
+            /// Returns the contained error message.
+            #[must_use]
+            #vis fn msg(&self) -> &str {
+                self.msg.as_str()
+            }
+
+            /// Returns the field that caused the error, if the error was caused by a field.
+            /// If several required fields were not set, returns the first of them.
+            #[must_use]
+            #vis fn field(&self) -> #core::option::Option<#builder_err_field_ty> {
+                self.field
+            }
+
+            /// Returns all the required fields that were not set.
+            #[must_use]
+            #vis fn missing_fields(&self) -> &[#builder_err_field_ty] {
+                &self.missing_fields
+            }
+        }
+
+        #[doc = #builder_err_field_ty_doc_string]
+        #[derive(
+            #core::fmt::Debug,
+            #core::clone::Clone,
+            #core::marker::Copy,
+            #core::cmp::PartialEq,
+            #core::cmp::Eq,
+            #core::hash::Hash,
+        )]
+        #vis enum #builder_err_field_ty {
+            #(#builder_err_field_variants)*
+        }
+
+        impl #core::fmt::Display for #builder_err_ty {
+            fn fmt(&self, f: &mut #core::fmt::Formatter<'_>) -> #core::fmt::Result {
+                write!(f, "{}", self.msg)
+            }
+        }
+
+        impl #core::error::Error for #builder_err_ty {
+            fn source(&self) -> #core::option::Option<&(dyn #core::error::Error + 'static)> {
+                match &self.source {
+                    #core::option::Option::Some(source) => #core::option::Option::Some(&**source),
+                    #core::option::Option::None => #core::option::Option::None,
+                }
+            }
+        }
+
+        #from_string_impl
+    }
+}
+
+fn get_builder_items(target: &BuilderTarget<'_>) -> (TokenStream2, TokenStream2) {
+    if target.struct_attrs.typestate.is_some() {
+        get_typestate_builder_items(target)
+    } else {
+        get_fallible_builder_items(target)
+    }
+}
+
+fn get_fallible_builder_items(target: &BuilderTarget<'_>) -> (TokenStream2, TokenStream2) {
+    let core = target.struct_attrs.core_path();
+    let vis = target.vis;
+    let caller_ty = target.caller_ty;
+    let builder_ty = &target.builder_ty;
+    let builder_err_ty = target.builder_err_ty;
+    let build_fn = target.struct_attrs.build_fn_ident();
+    let build_err_ty = target
+        .struct_attrs
+        .build_fn
+        .error
+        .as_ref()
+        .map_or_else(|| quote! { #builder_err_ty }, |error| quote! { #error });
+    let name = target.name();
+
+    let builder_fields = get_builder_fields(target);
+    let builder_setter_methods = target.fields_data.iter().map(|fd| {
+        get_field_setter_methods(
+            fd,
+            &name,
+            vis,
+            target.struct_attrs.setter_mode(),
+            target.struct_attrs,
+        )
+    });
+    let builder_getter_methods = target
+        .fields_data
+        .iter()
+        .map(|fd| get_field_getter_method(fd, &name, vis, target.struct_attrs));
+    let builder_clear_methods = target.fields_data.iter().map(|fd| {
+        get_field_clear_method(
+            fd,
+            &name,
+            vis,
+            target.struct_attrs.setter_mode(),
+            target.struct_attrs,
+        )
+    });
+    let custom_field_build_methods = target.fields_data.iter().map(get_custom_field_build_method);
+    let mutators = target.struct_attrs.mutator_fns();
+    let caller_ty_field_assignments = get_caller_ty_field_assignments(target);
+    let builder_ty_field_assignments = get_builder_ty_field_assignments(target);
+    let build_method_body = get_build_method_body(
+        &target.path,
+        caller_ty_field_assignments,
+        target.struct_attrs,
+    );
+
+    let missing_fields_check = get_missing_fields_check(target);
+    let constness = target.struct_attrs.constness();
+
+    let build_method_body = if target.has_infallible_build() || constness.is_some() {
+        quote! {
+            #build_method_body
+        }
+    } else if let Some(validate) = &target.struct_attrs.build_fn.validate {
+        quote! {
+            #missing_fields_check
+            let value = #build_method_body;
+            #validate(&value)?;
+            #core::result::Result::Ok(value)
+        }
+    } else {
+        quote! {
+            #missing_fields_check
+            #core::result::Result::Ok(#build_method_body)
+        }
+    };
+
+    let builder_ty_doc_string = format!("Builder type used to construct an instance of `{name}`.");
+    let builder_derive_attr = target.struct_attrs.builder_derive_attr();
+    let builder_serde_attrs = target.struct_attrs.serde.as_ref().map(|_| {
+        quote! {
+            #[derive(::serde::Deserialize)]
+            #[serde(default)]
+        }
+    });
+    let build_receiver = if target.struct_attrs.build_fn.by_ref.is_some() {
+        quote! { &self }
+    } else {
+        quote! { &mut self }
+    };
+
+    let wrap = target.struct_attrs.build_fn.wrap.as_ref();
+    let unwrapped_build_fn = if wrap.is_some() {
+        format_ident!("__derive_builder_build_unwrapped")
+    } else {
+        build_fn.clone()
+    };
+    let unwrapped_build_method_attrs = wrap.map(|_| quote! { #[doc(hidden)] });
+
+    let build_method = if target.has_infallible_build() || constness.is_some() {
+        let build_method_doc_string = if target.has_infallible_build() {
+            format!(
+                "Builds an instance of `{name}`. This method cannot fail,\n\
+                because all the fields of `{name}` are optional to be set."
+            )
+        } else {
+            format!(
+                "Builds an instance of `{name}`.\n\
+                # Panics\n\
+                Panics if any required setter was not called, which is a compile error\n\
+                when this method is evaluated in a const context."
+            )
+        };
+
+        let wrapped_build_method = wrap.map(|wrap| {
+            quote! {
+                #[doc = #build_method_doc_string]
+                #[must_use]
+                #vis fn #build_fn(#build_receiver) -> #wrap<#caller_ty> {
+                    #wrap::new(self.#unwrapped_build_fn())
+                }
+            }
+        });
+
+        quote! {
+            #[doc = #build_method_doc_string]
+            #[must_use]
+            #unwrapped_build_method_attrs
+            #vis #constness fn #unwrapped_build_fn(#build_receiver) -> #caller_ty {
+                #build_method_body
+            }
+
+            #wrapped_build_method
+
+            #[doc(hidden)]
+            #vis fn __derive_builder_build(
+                &mut self,
+            ) -> #core::result::Result<#caller_ty, #core::convert::Infallible> {
+                #core::result::Result::Ok(self.#unwrapped_build_fn())
+            }
+        }
+    } else {
+        let build_method_doc_string = format!(
+            "Attempts to build an instance of `{name}`.\n\
+            # Errors\n\
+            Returns an error if any required setter was not called{}.",
+            if target.struct_attrs.build_fn.validate.is_some() {
+                " or if the validation of the built value failed"
+            } else {
+                ""
+            }
+        );
+
+        let wrapped_build_method = wrap.map(|wrap| {
+            quote! {
+                #[doc = #build_method_doc_string]
+                #vis fn #build_fn(
+                    #build_receiver,
+                ) -> #core::result::Result<#wrap<#caller_ty>, #build_err_ty> {
+                    self.#unwrapped_build_fn().map(#wrap::new)
+                }
+            }
+        });
+
+        quote! {
+            #[doc = #build_method_doc_string]
+            #unwrapped_build_method_attrs
+            #vis fn #unwrapped_build_fn(
+                #build_receiver,
+            ) -> #core::result::Result<#caller_ty, #build_err_ty> {
+                #build_method_body
+            }
+
+            #wrapped_build_method
+
+            #[doc(hidden)]
+            #vis fn __derive_builder_build(
+                &mut self,
+            ) -> #core::result::Result<#caller_ty, #build_err_ty> {
+                self.#unwrapped_build_fn()
+            }
+        }
+    };
+
+    let items = quote! {
+        #[doc = #builder_ty_doc_string]
+        #builder_derive_attr
+        #builder_serde_attrs
+        #vis struct #builder_ty {
+            #(#builder_fields)*
+        }
+
+        impl #builder_ty {
+            #(#builder_setter_methods)*
+
+            #(#builder_getter_methods)*
+
+            #(#builder_clear_methods)*
+
+            #(#mutators)*
+
+            #build_method
+
+            #(#custom_field_build_methods)*
+        }
+    };
+
+    let init = quote! {
+        #builder_ty {
+            #(#builder_ty_field_assignments)*
+        }
+    };
+
+    (items, init)
+}
+
+fn get_typestate_builder_items(target: &BuilderTarget<'_>) -> (TokenStream2, TokenStream2) {
+    let core = target.struct_attrs.core_path();
+    let constness = target.struct_attrs.constness();
+    let method_lint_attrs = target.struct_attrs.method_lint_attrs();
+    let vis = target.vis;
+    let caller_ty = target.caller_ty;
+    let builder_ty = &target.builder_ty;
+    let build_fn = target.struct_attrs.build_fn_ident();
+    let name = target.name();
+
+    let state_fields = target
+        .fields_data
+        .iter()
+        .filter(|fd| target.is_state_field(fd))
+        .map(|fd| (fd, get_state_param(fd)))
+        .collect::<Vec<_>>();
+
+    let state_params = state_fields
+        .iter()
+        .map(|(_, state_param)| state_param)
+        .collect::<Vec<_>>();
+
+    // Const methods taking `self` by value cannot drop it, so the state
+    // parameters of the impls containing them must not need dropping.
+    let state_param_bound = |p: &Ident| {
+        if constness.is_some() {
+            quote! { #p: #core::marker::Copy }
+        } else {
+            quote! { #p }
+        }
+    };
+    let state_param_decls = state_params
+        .iter()
+        .map(|p| state_param_bound(p))
+        .collect::<Vec<_>>();
+
+    let builder_fields = get_builder_fields(target);
+    let builder_setter_methods = target
+        .fields_data
+        .iter()
+        .filter(|fd| !target.is_state_field(fd))
+        .map(|fd| get_field_setter_methods(fd, &name, vis, SetterMode::Owned, target.struct_attrs));
+    let builder_getter_methods = target
+        .fields_data
+        .iter()
+        .filter(|fd| !target.is_state_field(fd))
+        .map(|fd| get_field_getter_method(fd, &name, vis, target.struct_attrs));
+    let builder_clear_methods = target
+        .fields_data
+        .iter()
+        .filter(|fd| !target.is_state_field(fd))
+        .map(|fd| get_field_clear_method(fd, &name, vis, SetterMode::Owned, target.struct_attrs));
+    let mutators = target.struct_attrs.mutator_fns();
+    let caller_ty_field_assignments = get_caller_ty_field_assignments(target);
+    let builder_ty_field_assignments = get_builder_ty_field_assignments(target);
+    let build_method_body = get_build_method_body(
+        &target.path,
+        caller_ty_field_assignments,
+        target.struct_attrs,
+    );
+    let (build_method_body, build_method_ret_ty) = match &target.struct_attrs.build_fn.wrap {
+        Some(wrap) => (
+            quote! { #wrap::new(#build_method_body) },
+            quote! { #wrap<#caller_ty> },
+        ),
+        None => (quote! { #build_method_body }, quote! { #caller_ty }),
+    };
+
+    let state_setter_impls = state_fields.iter().map(|(fd, state_param)| {
+        let ident = &fd.ident;
+        let setter_ident = &fd.setter_ident;
+        let field_docs = fd.doc_attrs();
+        let field_name = fd.name();
+        let ty = fd.ty;
+
+        let other_state_params = state_params
+            .iter()
+            .filter(|p| **p != state_param)
+            .collect::<Vec<_>>();
+        let other_state_param_decls = other_state_params
+            .iter()
+            .map(|p| state_param_bound(p))
+            .collect::<Vec<_>>();
+        let unset_state_args = state_params.iter().map(|p| {
+            if *p == state_param {
+                quote! { () }
+            } else {
+                quote! { #p }
+            }
+        });
+        let set_state_args = state_params
+            .iter()
+            .map(|p| {
+                if *p == state_param {
+                    quote! { #ty }
+                } else {
+                    quote! { #p }
+                }
+            })
+            .collect::<Vec<_>>();
+        let other_field_moves = target
+            .fields_data
+            .iter()
+            .filter(|other_fd| other_fd.ident != *ident && !other_fd.is_computed())
+            .map(|other_fd| {
+                let other_ident = &other_fd.ident;
+                quote! { #other_ident: self.#other_ident, }
+            })
+            .collect::<Vec<_>>();
+
+        let doc_string = format!(
+            "Setter for the `{field_name}` field of `{name}`, required to be called before `{build_fn}()`."
+        );
+
+        let getter_ident = get_method_ident(
+            target.struct_attrs.rename_all,
+            &format!("get_{}", ident.unraw()),
+            ident.span(),
+        );
+        let getter_doc_string = format!(
+            "Returns the value of the `{field_name}` field of `{name}`. This method is only\n\
+            available once the setter for the field was called."
+        );
+
+        let (param_ty, value) = get_setter_param(ident, ty, &fd.setter, &core);
+
+        let try_method = fd.setter.try_into.as_ref().map(|_| {
+            let try_ident = get_method_ident(
+                target.struct_attrs.rename_all,
+                &format!("try_{}", fd.setter_name),
+                setter_ident.span(),
+            );
+
+            let try_method_doc_string = format!(
+                "Fallible setter for the `{field_name}` field of `{name}` that accepts any value\n\
+                that can be converted into the type of the field.\n\
+                # Errors\n\
+                Returns the conversion error if the value could not be converted."
+            );
+
+            quote! {
+                #field_docs
+                #[doc = #try_method_doc_string]
+                #method_lint_attrs
+                #vis fn #try_ident<__V: #core::convert::TryInto<#ty>>(
+                    self,
+                    #ident: __V,
+                ) -> #core::result::Result<#builder_ty<#(#set_state_args),*>, __V::Error> {
+                    #core::result::Result::Ok(#builder_ty {
+                        #ident: #core::convert::TryInto::try_into(#ident)?,
+                        #(#other_field_moves)*
+                    })
+                }
+            }
+        });
+
+        quote! {
+            impl<#(#other_state_param_decls),*> #builder_ty<#(#unset_state_args),*> {
+                #field_docs
+                #[doc = #doc_string]
+                #method_lint_attrs
+                #vis #constness fn #setter_ident(self, #ident: #param_ty) -> #builder_ty<#(#set_state_args),*> {
+                    #builder_ty {
+                        #ident: #value,
+                        #(#other_field_moves)*
+                    }
+                }
+
+                #try_method
+            }
+
+            impl<#(#other_state_params),*> #builder_ty<#(#set_state_args),*> {
+                #[doc = #getter_doc_string]
+                #[must_use]
+                #method_lint_attrs
+                #vis fn #getter_ident(&self) -> &#ty {
+                    &self.#ident
+                }
+            }
+        }
+    });
+
+    let set_state_args = state_fields.iter().map(|(fd, _)| fd.ty);
+
+    let builder_ty_doc_string = format!(
+        "Builder type used to construct an instance of `{name}`.\n\
+        The type parameters track which required setters were called."
+    );
+    let builder_derive_attr = target.struct_attrs.builder_derive_attr();
+
+    let build_method_doc_string = format!(
+        "Builds an instance of `{name}`. This method is only available\n\
+        once all the required setters were called."
+    );
+
+    let items = quote! {
+        #[doc = #builder_ty_doc_string]
+        #builder_derive_attr
+        #vis struct #builder_ty<#(#state_params = ()),*> {
+            #(#builder_fields)*
+        }
+
+        impl<#(#state_param_decls),*> #builder_ty<#(#state_params),*> {
+            #(#builder_setter_methods)*
+
+            #(#builder_getter_methods)*
+
+            #(#builder_clear_methods)*
+
+            #(#mutators)*
+        }
+
+        #(#state_setter_impls)*
+
+        impl #builder_ty<#(#set_state_args),*> {
+            #[doc = #build_method_doc_string]
+            #[must_use]
+            #vis #constness fn #build_fn(mut self) -> #build_method_ret_ty {
+                #build_method_body
+            }
+        }
+    };
+
+    let init = quote! {
+        #builder_ty {
+            #(#builder_ty_field_assignments)*
+        }
+    };
+
+    (items, init)
+}
+
+fn get_conversion_items(target: &BuilderTarget<'_>) -> TokenStream2 {
+    // Inherent methods cannot be added to a type defined in another crate,
+    // and converting its fields back would report their type mismatches twice.
+    if target.struct_attrs.remote.is_some() {
+        return quote! {};
+    }
+
+    let core = target.struct_attrs.core_path();
+    let vis = target.vis;
+    let caller_ty = target.caller_ty;
+    let builder_ty = &target.builder_ty;
+
+    let state_args = target
+        .fields_data
+        .iter()
+        .filter(|fd| target.is_state_field(fd))
+        .map(|fd| fd.ty)
+        .collect::<Vec<_>>();
+
+    let builder_ty_field_assignments = target.fields_data.iter().map(|fd| {
+        let member = &fd.member;
+        let ident = &fd.ident;
+
+        match fd.kind {
+            _ if target.is_state_field(fd) => quote! {
+                #ident: value.#member,
+            },
+            TypeKind::Regular => quote! {
+                #ident: #core::option::Option::Some(value.#member),
+            },
+            TypeKind::Optional | TypeKind::Repeated { .. } => quote! {
+                #ident: value.#member,
+            },
+            TypeKind::Computed { .. } => quote! {},
+            TypeKind::SubBuilder { .. } | TypeKind::Custom { .. } => quote! {
+                #ident: #core::convert::From::from(value.#member),
+            },
+        }
+    });
+
+    let custom_field_conversion_bounds = target
+        .fields_data
+        .iter()
+        .filter_map(|fd| {
+            if let TypeKind::Custom {
+                builder_field_ty, ..
+            } = &fd.kind
+            {
+                let ty = fd.ty;
+                Some(quote! { for<'__a> #builder_field_ty: #core::convert::From<#ty>, })
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let to_builder_method_doc_string = format!(
+        "Returns a builder type pre-populated with a copy of the values of this `{}`.",
+        target.name()
+    );
+
+    quote! {
+        impl #core::convert::From<#caller_ty> for #builder_ty<#(#state_args),*>
+        where
+            #(#custom_field_conversion_bounds)*
+        {
+            fn from(value: #caller_ty) -> Self {
+                #builder_ty {
+                    #(#builder_ty_field_assignments)*
+                }
+            }
+        }
+
+        impl #caller_ty {
+            #[doc = #to_builder_method_doc_string]
+            #[must_use]
+            #vis fn to_builder(&self) -> #builder_ty<#(#state_args),*>
+            where
+                for<'__a> #caller_ty: #core::clone::Clone,
+                #(#custom_field_conversion_bounds)*
+            {
+                #core::convert::From::from(#core::clone::Clone::clone(self))
+            }
+        }
+    }
+}
+
+fn get_apply_items(target: &BuilderTarget<'_>) -> TokenStream2 {
+    // A field or a mutator named `apply` keeps its method.
+    if target.struct_attrs.typestate.is_some() || target.has_method("apply") {
+        return quote! {};
+    }
+
+    let core = target.struct_attrs.core_path();
+    let vis = target.vis;
+    let caller_ty = target.caller_ty;
+    let builder_ty = &target.builder_ty;
+    let by_ref = target.struct_attrs.build_fn.by_ref.is_some();
+
+    let field_updates = target.fields_data.iter().map(|fd| {
+        let member = &fd.member;
+        let ident = &fd.ident;
+
+        // Like in the remote struct expression, a type mismatch between the
+        // fields of a remote struct and its mirror is reported on the mirror.
+        let span = fd.ty.span();
+
+        match (&fd.kind, by_ref) {
+            (TypeKind::Regular, false) => quote_spanned! { span=>
+                if let #core::option::Option::Some(value) = self.#ident.take() {
+                    target.#member = value;
+                }
+            },
+            (TypeKind::Regular, true) => quote_spanned! { span=>
+                if let #core::option::Option::Some(value) = &self.#ident {
+                    target.#member = #core::clone::Clone::clone(value);
+                }
+            },
+            (TypeKind::Optional, false) => quote_spanned! { span=>
+                if self.#ident.is_some() {
+                    target.#member = self.#ident.take();
+                }
+            },
+            (TypeKind::Optional, true) => quote_spanned! { span=>
+                if self.#ident.is_some() {
+                    target.#member = #core::clone::Clone::clone(&self.#ident);
+                }
+            },
+            (TypeKind::Repeated { .. }, false) => quote_spanned! { span=>
+                if !self.#ident.is_empty() {
+                    target.#member = #core::mem::take(&mut self.#ident);
+                }
+            },
+            (TypeKind::Repeated { .. }, true) => quote_spanned! { span=>
+                if !self.#ident.is_empty() {
+                    target.#member = #core::clone::Clone::clone(&self.#ident);
+                }
+            },
+            (TypeKind::SubBuilder { .. }, false) => quote_spanned! { span=>
+                self.#ident.apply(&mut target.#member);
+            },
+            (TypeKind::SubBuilder { .. }, true) => quote_spanned! { span=>
+                #core::clone::Clone::clone(&self.#ident).apply(&mut target.#member);
+            },
+            (TypeKind::Computed { .. } | TypeKind::Custom { .. }, _) => quote! {},
+        }
+    });
+
+    let receiver = if by_ref {
+        quote! { &self }
+    } else {
+        quote! { &mut self }
+    };
+
+    let apply_method_doc_string = format!(
+        "Overwrites the fields of an existing `{}` whose setters were called on this\n\
+        builder, leaving its other fields unchanged.",
+        target.name()
+    );
+
+    quote! {
+        impl #builder_ty {
+            #[doc = #apply_method_doc_string]
+            #vis fn apply(#receiver, target: &mut #caller_ty) {
+                #(#field_updates)*
+            }
+        }
+    }
+}
+
+fn get_remote_items(target: &BuilderTarget<'_>, mirror_ty: &Ident) -> TokenStream2 {
+    if target.struct_attrs.remote.is_none() {
+        return quote! {};
+    }
+
+    let caller_ty = target.caller_ty;
+    let constness = target.struct_attrs.constness();
+
+    // Type mismatches between the fields of both structs are reported here,
+    // on the type of the offending field of the mirror struct.
+    let field_moves = target.fields_data.iter().map(|fd| {
+        let member = &fd.member;
+        quote_spanned! { fd.ty.span()=> #member: value.#member, }
+    });
+
+    quote! {
+        impl #mirror_ty {
+            #constness fn __derive_builder_into_remote(value: #mirror_ty) -> #caller_ty {
+                #caller_ty {
+                    #(#field_moves)*
+                }
+            }
+        }
+    }
+}
+
+fn get_constructor_items(target: &BuilderTarget<'_>) -> TokenStream2 {
+    if target.struct_attrs.constructor.is_none() {
+        return quote! {};
+    }
+
+    let constness = target.struct_attrs.constness();
+    let vis = target.vis;
+    let caller_ty = target.caller_ty;
+    let build_fn = target.struct_attrs.build_fn_ident();
+
+    let required_fields = target
+        .fields_data
+        .iter()
+        .filter(|fd| fd.is_required())
+        .collect::<Vec<_>>();
+
+    let params = required_fields.iter().map(|fd| {
+        let ident = &fd.ident;
+        let ty = fd.ty;
+        quote! { #ident: #ty }
+    });
+
+    let build = if target.struct_attrs.typestate.is_some() {
+        let setter_calls = required_fields.iter().map(|fd| {
+            let ident = &fd.ident;
+            let setter_ident = &fd.setter_ident;
+            quote! { .#setter_ident(#ident) }
+        });
+
+        quote! { #caller_ty::builder() #(#setter_calls)*.#build_fn() }
+    } else {
+        let build_method_body = get_build_method_body(
+            &target.path,
+            get_constructor_field_assignments(target),
+            target.struct_attrs,
+        );
+
+        quote! { #build_method_body }
+    };
+
+    let doc_string = format!(
+        "Creates an instance of `{}` from the values of its required fields.\n\
+        The other fields are set as if their setters were not called.",
+        target.name()
+    );
+
+    quote! {
+        impl #caller_ty {
+            #[doc = #doc_string]
+            #[must_use]
+            #vis #constness fn new(#(#params),*) -> #caller_ty {
+                #build
+            }
+        }
+    }
+}
+
+fn get_constructor_field_assignments<'a>(
+    target: &'a BuilderTarget<'_>,
+) -> impl Iterator<Item = TokenStream2> + 'a {
+    let core = target.struct_attrs.core_path();
+    let builder_ty = &target.builder_ty;
+    let builder_ty_field_assignments = get_builder_ty_field_assignments(target).collect::<Vec<_>>();
+
+    target.fields_data.iter().map(move |fd| {
+        let member = &fd.member;
+        let ident = &fd.ident;
+
+        let value = match (&fd.kind, &fd.default) {
+            (TypeKind::Regular, None) => quote! { #ident },
+            (TypeKind::Regular | TypeKind::Optional, Some(FieldDefault::Expr(default)))
+            | (
+                TypeKind::Computed {
+                    default: Some(default),
+                },
+                _,
+            ) => quote! { #default },
+            (TypeKind::Optional, _) => quote! { #core::option::Option::None },
+            (TypeKind::Repeated { .. }, _) => {
+                let value = get_builder_field_unset_value(fd, target.struct_attrs);
+                quote! { #value }
+            }
+            (TypeKind::Custom { .. }, _) => {
+                let build_ident = get_custom_field_build_ident(fd);
+
+                quote! {
+                    #builder_ty {
+                        #(#builder_ty_field_assignments)*
+                    }.#build_ident()
+                }
+            }
+            // Nested builders cannot be used together with `constructor`.
+            (TypeKind::Regular, Some(FieldDefault::Trait))
+            | (TypeKind::Computed { default: None } | TypeKind::SubBuilder { .. }, _) => {
+                quote! { #core::default::Default::default() }
+            }
+        };
+
+        quote! { #member: #value, }
+    })
+}
+
+// Named after the index of the field rather than its name, since distinct
+// names such as `foo` and `foo_` have the same upper camel case form.
+fn get_state_param(fd: &FieldData<'_>) -> Ident {
+    format_ident!("__State{}", fd.index)
+}
+
+fn get_enum_builder_items(
+    data_enum: &DataEnum,
+    vis: &Visibility,
+    caller_ty: &Ident,
+    builder_ty: &Ident,
+    builder_err_ty: &Ident,
+    builder_err_field_ty: &Ident,
+    struct_attrs: &StructAttrs,
+) -> Result<(TokenStream2, TokenStream2, Vec<TokenStream2>), Error> {
+    if let Some(mutators) = &struct_attrs.mutators {
+        return Err(Error::new_spanned(
+            &mutators.key,
+            "`mutators` can only be used on structs",
+        ));
+    }
+
+    if let Some(default_from) = &struct_attrs.default_from {
+        return Err(Error::new_spanned(
+            &default_from.key,
+            "`default_from` can only be used on structs",
+        ));
+    }
+
+    if let Some(constructor) = &struct_attrs.constructor {
+        return Err(Error::new_spanned(
+            constructor,
+            "`constructor` can only be used on structs",
+        ));
+    }
+
+    if let Some(remote) = &struct_attrs.remote {
+        return Err(Error::new_spanned(
+            &remote.key,
+            "`remote` can only be used on structs",
+        ));
+    }
+
+    let core = struct_attrs.core_path();
+    let mut variant_items = Vec::with_capacity(data_enum.variants.len());
+    let mut variant_methods = Vec::with_capacity(data_enum.variants.len());
+    let mut err_field_variants = Vec::new();
+    let constness = struct_attrs.constness();
+    let enum_ty = Path::from(caller_ty.clone());
+
+    let variants_fields_data = collect_results(
+        data_enum
+            .variants
+            .iter()
+            .map(|variant| get_fields_data(&variant.fields, struct_attrs)),
+    )?;
+
+    // The error field variants of all the enum variants share a single enum.
+    let mut taken_err_field_variants = HashSet::new();
+
+    for (variant, fields_data) in data_enum.variants.iter().zip(variants_fields_data) {
+        let variant_ident = &variant.ident;
+
+        let target = BuilderTarget {
+            vis,
+            caller_ty: &enum_ty,
+            path: parse_quote!(#caller_ty::#variant_ident),
+            builder_ty: format_ident!("{}{}Builder", caller_ty, variant_ident),
+            builder_err_ty,
+            builder_err_field_ty,
+            err_field_variant_idents: get_err_field_variant_idents(
+                &fields_data,
+                Some(variant_ident),
+                &mut taken_err_field_variants,
+            ),
+            fields_data,
+            struct_attrs,
+        };
+
+        let (items, init) = get_builder_items(&target);
+        err_field_variants.extend(target.err_field_variants());
+
+        let variant_builder_ty = &target.builder_ty;
+        let method_ident = new_ident(
+            &to_snake_case(&variant_ident.unraw().to_string()),
+            variant_ident.span(),
+        );
+
+        let method_doc_string = format!(
+            "Returns a builder type used to construct an instance of `{}`.",
+            target.name()
+        );
+
+        variant_items.push(quote! {
+            #items
+
+            impl #core::default::Default for #variant_builder_ty {
+                fn default() -> #variant_builder_ty {
+                    #init
+                }
+            }
+        });
+        variant_methods.push(quote! {
+            #[doc = #method_doc_string]
+            #[must_use]
+            #vis #constness fn #method_ident(self) -> #variant_builder_ty {
+                #init
+            }
+        });
+    }
+
+    let builder_ty_doc_string =
+        format!("Builder type used to choose which variant of `{caller_ty}` to construct.");
+    let builder_derive_attr = struct_attrs.builder_derive_attr();
+
+    let items = quote! {
+        #(#variant_items)*
+
+        #[doc = #builder_ty_doc_string]
+        #builder_derive_attr
+        #vis struct #builder_ty;
+
+        impl #builder_ty {
+            #(#variant_methods)*
+        }
+    };
+
+    Ok((items, quote! { #builder_ty }, err_field_variants))
+}
+
+fn check_input_is_not_generic(input: &DeriveInput) -> Result<(), Error> {
+    if input.generics.params.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::new_spanned(
+            &input.generics,
+            "expected struct without generic type parameters",
+        ))
+    }
+}
+
+const STRUCT_KEYS: &[(&str, &str)] = &[
+    ("name", "`name = \"...\"`"),
+    ("vis", "`vis = \"...\"`"),
+    ("derive", "`derive(...)`"),
+    ("default_from", "`default_from = \"...\"`"),
+    ("rename_all", "`rename_all = \"...\"`"),
+    ("setter", "`setter(...)`"),
+    ("finalize", "`finalize = \"...\"`"),
+    ("mutators", "`mutators { ... }`"),
+    ("serde", "`serde`"),
+    ("const", "`const`"),
+    ("no_std", "`no_std`"),
+    ("constructor", "`constructor`"),
+    ("remote", "`remote = \"...\"`"),
+    ("typestate", "`typestate`"),
+    ("build_fn", "`build_fn(...)`"),
+];
+
+const STRUCT_SETTER_KEYS: &[(&str, &str)] = &[
+    ("prefix", "`prefix = \"...\"`"),
+    ("owned", "`owned`"),
+    ("mutable", "`mutable`"),
+];
+
+const BUILD_FN_KEYS: &[(&str, &str)] = &[
+    ("name", "`name = \"...\"`"),
+    ("error", "`error = \"...\"`"),
+    ("validate", "`validate = \"...\"`"),
+    ("by_ref", "`by_ref`"),
+    ("wrap", "`wrap = \"...\"`"),
+];
+
+const FIELD_KEYS: &[(&str, &str)] = &[
+    ("each", "`each = \"...\"`"),
+    ("default", "`default`, `default = \"...\"`"),
+    ("setter", "`setter(...)`"),
+    ("field", "`field(ty = \"...\", build = \"...\")`"),
+    ("sub_builder", "`sub_builder`"),
+    ("required", "`required`"),
+    ("env", "`env = \"...\"`"),
+    ("computed", "`computed`, `computed = \"...\"`"),
+    ("skip", "`skip`, `skip = \"...\"`"),
+];
+
+const FIELD_SETTER_KEYS: &[(&str, &str)] = &[
+    ("name", "`name = \"...\"`"),
+    ("into", "`into`"),
+    ("strip_option", "`strip_option`"),
+    ("try_into", "`try_into`"),
+    ("append_only", "`append_only`"),
+];
+
+const CUSTOM_FIELD_KEYS: &[(&str, &str)] =
+    &[("ty", "`ty = \"...\"`"), ("build", "`build = \"...\"`")];
+
+fn get_struct_attrs(input: &DeriveInput) -> Result<StructAttrs, Error> {
+    let mut struct_attrs = StructAttrs::default();
+
+    if let Some(attr) = get_builder_attr(
+        &input.attrs,
+        "multiple `builder` attributes on the same struct",
+    )? {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                check_key_is_not_duplicated(&meta, struct_attrs.name.is_some())?;
+                struct_attrs.name = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("vis") {
+                check_key_is_not_duplicated(&meta, struct_attrs.vis.is_some())?;
+                struct_attrs.vis = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("derive") {
+                check_key_is_not_duplicated(&meta, struct_attrs.derive.is_some())?;
+
+                let mut derives = Vec::new();
+                meta.parse_nested_meta(|meta| {
+                    if meta
+                        .path
+                        .segments
+                        .last()
+                        .is_some_and(|segment| segment.ident == "Default")
+                    {
+                        return Err(meta.error("the builder already implements `Default`"));
+                    }
+
+                    derives.push(meta.path);
+                    Ok(())
+                })?;
+
+                struct_attrs.derive = Some(derives);
+                Ok(())
+            } else if meta.path.is_ident("default_from") {
+                check_key_is_not_duplicated(&meta, struct_attrs.default_from.is_some())?;
+
+                struct_attrs.default_from = Some(DefaultFromAttr {
+                    key: meta.path.clone(),
+                    template: meta.value()?.parse::<LitStr>()?.parse()?,
+                });
+
+                Ok(())
+            } else if meta.path.is_ident("rename_all") {
+                check_key_is_not_duplicated(&meta, struct_attrs.rename_all.is_some())?;
+                struct_attrs.rename_all = Some(RenameRule::from_lit(&meta.value()?.parse()?)?);
+                Ok(())
+            } else if meta.path.is_ident("setter") {
+                meta.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("prefix") {
+                        check_key_is_not_duplicated(&meta, struct_attrs.setter.prefix.is_some())?;
+
+                        let prefix = meta.value()?.parse::<LitStr>()?;
+
+                        if !prefix.value().is_empty()
+                            && syn::parse_str::<Ident>(&prefix.value()).is_err()
+                        {
+                            return Err(Error::new_spanned(prefix, "expected identifier prefix"));
+                        }
+
+                        struct_attrs.setter.prefix = Some(prefix.value());
+                        Ok(())
+                    } else if meta.path.is_ident("owned") {
+                        check_key_is_not_duplicated(&meta, struct_attrs.setter.owned.is_some())?;
+                        struct_attrs.setter.owned = Some(meta.path);
+                        Ok(())
+                    } else if meta.path.is_ident("mutable") {
+                        check_key_is_not_duplicated(&meta, struct_attrs.setter.mutable.is_some())?;
+                        struct_attrs.setter.mutable = Some(meta.path);
+                        Ok(())
+                    } else {
+                        Err(get_unrecognized_key_err(&meta, STRUCT_SETTER_KEYS))
+                    }
+                })
+            } else if meta.path.is_ident("finalize") {
+                check_key_is_not_duplicated(&meta, struct_attrs.finalize.is_some())?;
+                struct_attrs.finalize = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("mutators") {
+                check_key_is_not_duplicated(&meta, struct_attrs.mutators.is_some())?;
+
+                let content;
+                if meta.input.peek(token::Brace) {
+                    braced!(content in meta.input);
+                } else {
+                    parenthesized!(content in meta.input);
+                }
+
+                let mut fns = Vec::new();
+                while !content.is_empty() {
+                    fns.push(content.parse()?);
+                }
+
+                struct_attrs.mutators = Some(MutatorsAttr {
+                    key: meta.path,
+                    fns,
+                });
+
+                Ok(())
+            } else if meta.path.is_ident("serde") {
+                check_key_is_not_duplicated(&meta, struct_attrs.serde.is_some())?;
+                struct_attrs.serde = Some(meta.path);
+                Ok(())
+            } else if meta.path.is_ident("const") {
+                check_key_is_not_duplicated(&meta, struct_attrs.constant.is_some())?;
+                struct_attrs.constant = Some(meta.path);
+                Ok(())
+            } else if meta.path.is_ident("no_std") {
+                check_key_is_not_duplicated(&meta, struct_attrs.no_std.is_some())?;
+                struct_attrs.no_std = Some(meta.path);
+                Ok(())
+            } else if meta.path.is_ident("constructor") {
+                check_key_is_not_duplicated(&meta, struct_attrs.constructor.is_some())?;
+                struct_attrs.constructor = Some(meta.path);
+                Ok(())
+            } else if meta.path.is_ident("remote") {
+                check_key_is_not_duplicated(&meta, struct_attrs.remote.is_some())?;
+
+                struct_attrs.remote = Some(RemoteAttr {
+                    key: meta.path.clone(),
+                    ty: meta.value()?.parse::<LitStr>()?.parse()?,
+                });
+
+                Ok(())
+            } else if meta.path.is_ident("typestate") {
+                check_key_is_not_duplicated(&meta, struct_attrs.typestate.is_some())?;
+                struct_attrs.typestate = Some(meta.path);
+                Ok(())
+            } else if meta.path.is_ident("build_fn") {
+                meta.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("name") {
+                        check_key_is_not_duplicated(&meta, struct_attrs.build_fn.name.is_some())?;
+                        struct_attrs.build_fn.name =
+                            Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("error") {
+                        check_key_is_not_duplicated(&meta, struct_attrs.build_fn.error.is_some())?;
+
+                        struct_attrs.build_fn.error =
+                            Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("validate") {
+                        check_key_is_not_duplicated(
+                            &meta,
+                            struct_attrs.build_fn.validate.is_some(),
+                        )?;
+
+                        struct_attrs.build_fn.validate =
+                            Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("by_ref") {
+                        check_key_is_not_duplicated(&meta, struct_attrs.build_fn.by_ref.is_some())?;
+                        struct_attrs.build_fn.by_ref = Some(meta.path);
+                        Ok(())
+                    } else if meta.path.is_ident("wrap") {
+                        check_key_is_not_duplicated(&meta, struct_attrs.build_fn.wrap.is_some())?;
+                        struct_attrs.build_fn.wrap =
+                            Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                        Ok(())
+                    } else {
+                        Err(get_unrecognized_key_err(&meta, BUILD_FN_KEYS))
+                    }
+                })
+            } else {
+                Err(get_unrecognized_key_err(&meta, STRUCT_KEYS))
+            }
+        })?;
+    }
+
+    struct_attrs.check_compatibility()?;
+    Ok(struct_attrs)
+}
+
+fn get_fields_data<'a>(
+    fields: &'a Fields,
+    struct_attrs: &StructAttrs,
+) -> Result<Vec<FieldData<'a>>, Error> {
+    let mut field_to_field_data = FieldToFieldData::new(struct_attrs);
+
+    collect_results(
+        fields
+            .iter()
+            .enumerate()
+            .map(|(i, f)| field_to_field_data.convert(i, f)),
+    )
+}
+
+fn collect_results<T>(results: impl Iterator<Item = Result<T, Error>>) -> Result<Vec<T>, Error> {
+    let mut values = Vec::new();
+    let mut errors: Option<Error> = None;
+
+    for result in results {
+        match (result, &mut errors) {
+            (Ok(value), _) => values.push(value),
+            (Err(err), Some(errors)) => errors.combine(err),
+            (Err(err), None) => errors = Some(err),
+        }
+    }
+
+    errors.map_or(Ok(values), Err)
+}
+
+fn get_builder_fields<'a>(
+    target: &'a BuilderTarget<'_>,
+) -> impl Iterator<Item = TokenStream2> + 'a {
+    target.fields_data.iter().map(|fd| {
+        let core = target.struct_attrs.core_path();
+        let alloc = target.struct_attrs.alloc_path();
+        let ident = &fd.ident;
+        let ty = fd.ty;
+        let docs = &fd.docs;
+
+        let field = match &fd.kind {
+            _ if target.is_state_field(fd) => {
+                let state_param = get_state_param(fd);
+                quote! { #ident: #state_param, }
+            }
+            TypeKind::Repeated {
+                collection: Collection::Vec,
+                ..
+            } => quote! { #ident: #alloc::vec::Vec<#ty>, },
+            TypeKind::Repeated {
+                collection: Collection::Map { map_ty, .. },
+                ..
+            } => quote! { #ident: #map_ty, },
+            TypeKind::Computed { .. } => return quote! {},
+            TypeKind::SubBuilder { builder_ty } => quote! { #ident: #builder_ty, },
+            TypeKind::Custom {
+                builder_field_ty, ..
+            } => quote! { #ident: #builder_field_ty, },
+            TypeKind::Regular | TypeKind::Optional => {
+                quote! { #ident: #core::option::Option<#ty>, }
+            }
+        };
+
+        quote! {
+            #(#docs)*
+            #field
+        }
+    })
+}
+
+fn get_field_setter_methods(
+    fd: &FieldData<'_>,
+    caller_name: &str,
+    vis: &Visibility,
+    mode: SetterMode,
+    struct_attrs: &StructAttrs,
+) -> TokenStream2 {
+    let core = struct_attrs.core_path();
+    let alloc = struct_attrs.alloc_path();
+    let receiver = mode.receiver();
+    let return_ty = mode.return_ty();
+    let method_lint_attrs = struct_attrs.method_lint_attrs();
+
+    let ident = &fd.ident;
+    let setter_ident = &fd.setter_ident;
+    let field_docs = fd.doc_attrs();
+    let name = fd.name();
+    let ty = fd.ty;
+
+    if let TypeKind::Repeated {
+        ident: one_at_a_time_ident,
+        collection,
+    } = &fd.kind
+    {
+        let one_at_a_time_method = match collection {
+            Collection::Vec => {
+                let one_at_a_time_method_doc_string = format!(
+                    "Setter for the `{name}` field of `{caller_name}` that can append values to `{name}`,\n\
+                    one at a time. It is optional to be called. If no setter is called for the `{name}`\n\
+                    field, then the field stays empty."
+                );
+
+                let (one_at_a_time_param_ty, one_at_a_time_value) =
+                    get_setter_param(one_at_a_time_ident, ty, &fd.setter, &core);
+
+                let try_method = fd.setter.try_into.as_ref().map(|_| {
+                    let try_ident = get_method_ident(
+                        struct_attrs.rename_all,
+                        &format!("try_{}", one_at_a_time_ident.unraw()),
+                        one_at_a_time_ident.span(),
+                    );
+
+                    let try_method_doc_string = format!(
+                        "Fallible setter for the `{name}` field of `{caller_name}` that can append values\n\
+                        to `{name}`, one at a time, accepting any value that can be converted into\n\
+                        the type of the values.\n\
+                        # Errors\n\
+                        Returns the conversion error if the value could not be converted, in which case\n\
+                        the field is left unchanged."
+                    );
+
+                    quote! {
+                        #field_docs
+                        #[doc = #try_method_doc_string]
+                        #method_lint_attrs
+                        #vis fn #try_ident<__V: #core::convert::TryInto<#ty>>(
+                            #receiver,
+                            #one_at_a_time_ident: __V,
+                        ) -> #core::result::Result<#return_ty, __V::Error> {
+                            self.#ident.push(#core::convert::TryInto::try_into(#one_at_a_time_ident)?);
+                            #core::result::Result::Ok(self)
+                        }
+                    }
+                });
+
+                quote! {
+                    #field_docs
+                    #[doc = #one_at_a_time_method_doc_string]
+                    #vis fn #one_at_a_time_ident(#receiver, #one_at_a_time_ident: #one_at_a_time_param_ty) -> #return_ty {
+                        self.#ident.push(#one_at_a_time_value);
+                        self
+                    }
+
+                    #try_method
+                }
+            }
+            Collection::Map { key_ty, .. } => {
+                let one_at_a_time_method_doc_string = format!(
+                    "Setter for the `{name}` field of `{caller_name}` that can insert entries into `{name}`,\n\
+                    one at a time, replacing the value of an existing entry with the same key.\n\
+                    It is optional to be called. If no setter is called for the `{name}` field,\n\
+                    then the field stays empty."
+                );
+
+                let key_ident = Ident::new("key", Span::call_site());
+                let value_ident = Ident::new("value", Span::call_site());
+
+                let (key_param_ty, key_value) =
+                    get_setter_param(&key_ident, key_ty, &fd.setter, &core);
+                let (value_param_ty, value_value) =
+                    get_setter_param(&value_ident, ty, &fd.setter, &core);
+
+                let try_method = fd.setter.try_into.as_ref().map(|_| {
+                    let try_ident = get_method_ident(
+                        struct_attrs.rename_all,
+                        &format!("try_{}", one_at_a_time_ident.unraw()),
+                        one_at_a_time_ident.span(),
+                    );
+
+                    let try_method_doc_string = format!(
+                        "Fallible setter for the `{name}` field of `{caller_name}` that can insert entries\n\
+                        into `{name}`, one at a time, accepting any value that can be converted into\n\
+                        the type of the values of the map.\n\
+                        # Errors\n\
+                        Returns the conversion error if the value could not be converted, in which case\n\
+                        the field is left unchanged."
+                    );
+
+                    quote! {
+                        #field_docs
+                        #[doc = #try_method_doc_string]
+                        #method_lint_attrs
+                        #vis fn #try_ident<__V: #core::convert::TryInto<#ty>>(
+                            #receiver,
+                            #key_ident: #key_param_ty,
+                            #value_ident: __V,
+                        ) -> #core::result::Result<#return_ty, __V::Error> {
+                            let #value_ident = #core::convert::TryInto::try_into(#value_ident)?;
+                            self.#ident.insert(#key_value, #value_ident);
+                            #core::result::Result::Ok(self)
+                        }
+                    }
+                });
+
+                quote! {
+                    #field_docs
+                    #[doc = #one_at_a_time_method_doc_string]
+                    #vis fn #one_at_a_time_ident(
+                        #receiver,
+                        #key_ident: #key_param_ty,
+                        #value_ident: #value_param_ty,
+                    ) -> #return_ty {
+                        self.#ident.insert(#key_value, #value_value);
+                        self
+                    }
+
+                    #try_method
+                }
+            }
+        };
+
+        let extend_ident = get_method_ident(
+            struct_attrs.rename_all,
+            &format!("extend_{}", ident.unraw()),
+            ident.span(),
+        );
+
+        let extend_method_doc_string = format!(
+            "Setter for the `{name}` field of `{caller_name}` that can add all the {} yielded\n\
+            by an iterator to `{name}`. It is optional to be called. If no setter is called for\n\
+            the `{name}` field, then the field stays empty.",
+            match collection {
+                Collection::Vec => "values",
+                Collection::Map { .. } => "entries",
+            }
+        );
+
+        let extend_item_ty = match collection {
+            Collection::Vec => quote! { #ty },
+            Collection::Map { key_ty, .. } => quote! { (#key_ty, #ty) },
+        };
+
+        let extend_method = quote! {
+            #field_docs
+            #[doc = #extend_method_doc_string]
+            #method_lint_attrs
+            #vis fn #extend_ident(
+                #receiver,
+                #ident: impl #core::iter::IntoIterator<Item = #extend_item_ty>,
+            ) -> #return_ty {
+                #core::iter::Extend::extend(&mut self.#ident, #ident);
+                self
+            }
+        };
+
+        if one_at_a_time_ident == setter_ident || fd.setter.append_only.is_some() {
+            quote! {
+                #one_at_a_time_method
+
+                #extend_method
+            }
+        } else {
+            let all_at_once_method_doc_string = format!(
+                "Setter for the `{name}` field of `{caller_name}` that can assign multiple values,\n\
+                all at once, overwriting the previous values. It is optional to be called.\n\
+                If no setter is called for the `{name}` field, then the field stays empty."
+            );
+
+            let all_at_once_ty = match collection {
+                Collection::Vec => parse_quote!(#alloc::vec::Vec<#ty>),
+                Collection::Map { map_ty, .. } => (*map_ty).clone(),
+            };
+
+            let (param_ty, value) = get_setter_param(ident, &all_at_once_ty, &fd.setter, &core);
+
+            quote! {
+                #one_at_a_time_method
+
+                #extend_method
+
+                #field_docs
+                #[doc = #all_at_once_method_doc_string]
+                #method_lint_attrs
+                #vis fn #setter_ident(#receiver, #ident: #param_ty) -> #return_ty {
+                    self.#ident = #value;
+                    self
+                }
+            }
+        }
+    } else if let TypeKind::Computed { .. } = &fd.kind {
+        quote! {}
+    } else if let TypeKind::Custom {
+        builder_field_ty, ..
+    } = &fd.kind
+    {
+        let doc_string = format!(
+            "Setter for the intermediate value of the `{name}` field of `{caller_name}`,\n\
+            optional to be called. If this setter is not called, the intermediate value\n\
+            is its default value."
+        );
+
+        let (param_ty, value) = get_setter_param(ident, builder_field_ty, &fd.setter, &core);
+
+        quote! {
+            #field_docs
+            #[doc = #doc_string]
+            #method_lint_attrs
+            #vis fn #setter_ident(#receiver, #ident: #param_ty) -> #return_ty {
+                self.#ident = #value;
+                self
+            }
+        }
+    } else if let TypeKind::SubBuilder { builder_ty } = &fd.kind {
+        let accessor_ident = get_method_ident(
+            struct_attrs.rename_all,
+            &format!("{}_builder", ident.unraw()),
+            ident.span(),
+        );
+
+        let doc_string = format!(
+            "Returns the builder used to construct the `{name}` field of `{caller_name}`.\n\
+            The field is built when `{caller_name}` is built."
+        );
+
+        quote! {
+            #field_docs
+            #[doc = #doc_string]
+            #method_lint_attrs
+            #vis fn #accessor_ident(&mut self) -> &mut #builder_ty {
+                &mut self.#ident
+            }
+        }
+    } else {
+        let doc_string = if let Some(env) = &fd.env {
+            format!(
+                "Setter for the `{name}` field of `{caller_name}`. If this setter is not called,\n\
+                the value is parsed from the `{}` environment variable, if it is set.",
+                env.value()
+            )
+        } else if let (TypeKind::Optional, None) = (&fd.kind, &fd.default) {
+            format!(
+                "Setter for the `{name}` field of `{caller_name}`, optional to be called.\n\
+                If this setter is not called, the field's value remains\n\
+                [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None)."
+            )
+        } else if fd.default.is_some() {
+            format!(
+                "Setter for the `{name}` field of `{caller_name}`, optional to be called.\n\
+                If this setter is not called, the field is set to its default value."
+            )
+        } else {
+            format!("Setter for the `{name}` field of `{caller_name}`, required to be called.")
+        };
+
+        let (param_ty, value) = get_setter_param(ident, ty, &fd.setter, &core);
+
+        let try_method = fd.setter.try_into.as_ref().map(|_| {
+            let try_ident = get_method_ident(
+                struct_attrs.rename_all,
+                &format!("try_{}", fd.setter_name),
+                setter_ident.span(),
+            );
+
+            let try_method_doc_string = format!(
+                "Fallible setter for the `{name}` field of `{caller_name}` that accepts any value\n\
+                that can be converted into the type of the field.\n\
+                # Errors\n\
+                Returns the conversion error if the value could not be converted, in which case\n\
+                the field is left unchanged."
+            );
+
+            quote! {
+                #field_docs
+                #[doc = #try_method_doc_string]
+                #method_lint_attrs
+                #vis fn #try_ident<__V: #core::convert::TryInto<#ty>>(
+                    #receiver,
+                    #ident: __V,
+                ) -> #core::result::Result<#return_ty, __V::Error> {
+                    self.#ident = #core::option::Option::Some(
+                        #core::convert::TryInto::try_into(#ident)?
+                    );
+
+                    #core::result::Result::Ok(self)
+                }
+            }
+        });
+
+        let constness = struct_attrs.constness();
+
+        let maybe_method = if let TypeKind::Optional = &fd.kind {
+            let maybe_ident = get_method_ident(
+                struct_attrs.rename_all,
+                &format!("maybe_{}", fd.setter_name),
+                setter_ident.span(),
+            );
+
+            let maybe_method_doc_string = format!(
+                "Setter for the `{name}` field of `{caller_name}` that accepts the\n\
+                [`Option`](https://doc.rust-lang.org/std/option/enum.Option.html) itself,\n\
+                which is useful to forward a value that is already optional."
+            );
+
+            Some(quote! {
+                #field_docs
+                #[doc = #maybe_method_doc_string]
+                #method_lint_attrs
+                #vis #constness fn #maybe_ident(
+                    #receiver,
+                    #ident: #core::option::Option<#ty>,
+                ) -> #return_ty {
+                    self.#ident = #ident;
+                    self
+                }
+            })
+        } else {
+            None
+        };
+
+        quote! {
+            #field_docs
+            #[doc = #doc_string]
+            #method_lint_attrs
+            #vis #constness fn #setter_ident(#receiver, #ident: #param_ty) -> #return_ty {
+                self.#ident = #core::option::Option::Some(#value);
+                self
+            }
+
+            #try_method
+
+            #maybe_method
+        }
+    }
+}
+
+fn get_field_getter_method(
+    fd: &FieldData<'_>,
+    caller_name: &str,
+    vis: &Visibility,
+    struct_attrs: &StructAttrs,
+) -> TokenStream2 {
+    let core = struct_attrs.core_path();
+    let method_lint_attrs = struct_attrs.method_lint_attrs();
+    let ident = &fd.ident;
+    let getter_ident = get_method_ident(
+        struct_attrs.rename_all,
+        &format!("get_{}", ident.unraw()),
+        ident.span(),
+    );
+    let name = fd.name();
+    let ty = fd.ty;
+
+    let (return_ty, value, doc_string) = match &fd.kind {
+        TypeKind::Repeated {
+            collection: Collection::Vec,
+            ..
+        } => (
+            quote! { &[#ty] },
+            quote! { &self.#ident },
+            format!("Returns the values added so far to the `{name}` field of `{caller_name}`."),
+        ),
+        TypeKind::Repeated {
+            collection: Collection::Map { map_ty, .. },
+            ..
+        } => (
+            quote! { &#map_ty },
+            quote! { &self.#ident },
+            format!("Returns the entries inserted so far into the `{name}` field of `{caller_name}`."),
+        ),
+        TypeKind::Custom {
+            builder_field_ty, ..
+        } => (
+            quote! { &#builder_field_ty },
+            quote! { &self.#ident },
+            format!("Returns the intermediate value of the `{name}` field of `{caller_name}`."),
+        ),
+        TypeKind::Computed { .. } | TypeKind::SubBuilder { .. } => return quote! {},
+        TypeKind::Regular | TypeKind::Optional => (
+            quote! { #core::option::Option<&#ty> },
+            quote! { self.#ident.as_ref() },
+            format!(
+                "Returns the value of the `{name}` field of `{caller_name}` if its setter was called,\n\
+                or [`None`](https://doc.rust-lang.org/std/option/enum.Option.html#variant.None) otherwise."
+            ),
+        ),
+    };
+
+    quote! {
+        #[doc = #doc_string]
+        #[must_use]
+        #method_lint_attrs
+        #vis fn #getter_ident(&self) -> #return_ty {
+            #value
+        }
+    }
+}
+
+fn get_field_clear_method(
+    fd: &FieldData<'_>,
+    caller_name: &str,
+    vis: &Visibility,
+    mode: SetterMode,
+    struct_attrs: &StructAttrs,
+) -> TokenStream2 {
+    let value = match get_builder_field_unset_value(fd, struct_attrs) {
+        Some(value) => value,
+        None => return quote! {},
+    };
+
+    let receiver = mode.receiver();
+    let return_ty = mode.return_ty();
+    let method_lint_attrs = struct_attrs.method_lint_attrs();
+    let ident = &fd.ident;
+    let clear_ident = get_method_ident(
+        struct_attrs.rename_all,
+        &format!("clear_{}", ident.unraw()),
+        ident.span(),
+    );
+    let name = fd.name();
+
+    let doc_string = match &fd.kind {
+        TypeKind::Repeated { .. } => {
+            format!("Removes everything added so far to the `{name}` field of `{caller_name}`.")
+        }
+        TypeKind::SubBuilder { .. } => {
+            format!("Resets the builder used to construct the `{name}` field of `{caller_name}`.")
+        }
+        TypeKind::Custom { .. } => format!(
+            "Resets the intermediate value of the `{name}` field of `{caller_name}`\n\
+            to its default value."
+        ),
+        _ => format!(
+            "Reverts the `{name}` field of `{caller_name}` to the state it had\n\
+            before any of its setters was called."
+        ),
+    };
+
+    let constness = match fd.kind {
+        TypeKind::Regular | TypeKind::Optional => struct_attrs.constness(),
+        _ => None,
+    };
+
+    quote! {
+        #[doc = #doc_string]
+        #method_lint_attrs
+        #vis #constness fn #clear_ident(#receiver) -> #return_ty {
+            self.#ident = #value;
+            self
+        }
+    }
+}
+
+fn get_custom_field_build_method(fd: &FieldData<'_>) -> TokenStream2 {
+    if let TypeKind::Custom { build, .. } = &fd.kind {
+        let build_ident = get_custom_field_build_ident(fd);
+        let ty = fd.ty;
+
+        quote! {
+            fn #build_ident(self) -> #ty {
+                #build
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
+
+fn get_custom_field_build_ident(fd: &FieldData<'_>) -> Ident {
+    format_ident!("__derive_builder_build_{}", fd.ident.unraw())
+}
+
+fn get_setter_param(
+    param: &Ident,
+    ty: &Type,
+    setter: &SetterAttrs,
+    core: &TokenStream2,
+) -> (impl ToTokens, impl ToTokens) {
+    if setter.into.is_some() {
+        (
+            quote! { impl #core::convert::Into<#ty> },
+            quote! { #core::convert::Into::into(#param) },
+        )
+    } else {
+        (quote! { #ty }, quote! { #param })
+    }
+}
+
+fn get_caller_ty_field_assignments<'a>(
+    target: &'a BuilderTarget<'_>,
+) -> impl Iterator<Item = impl ToTokens> + 'a {
+    let builder_err_ty = target.builder_err_ty;
+    let builder_err_field_ty = target.builder_err_field_ty;
+
+    let by_ref = target.struct_attrs.build_fn.by_ref.is_some();
+    let is_const = target.struct_attrs.constant.is_some();
+
+    target
+        .fields_data_with_err_field_variants()
+        .map(move |(fd, err_field_variant)| {
+            let core = target.struct_attrs.core_path();
+            let member = &fd.member;
+            let ident = &fd.ident;
+            let err_field = quote! { #builder_err_field_ty::#err_field_variant };
+
+            let value = if by_ref {
+                quote! { #core::clone::Clone::clone(&self.#ident) }
+            } else if let TypeKind::Repeated { .. } | TypeKind::Custom { .. } = &fd.kind {
+                quote! { #core::mem::take(&mut self.#ident) }
+            } else {
+                quote! { self.#ident.take() }
+            };
+
+            let value = if let Some(env) = &fd.env {
+                let ty = fd.ty;
+                let err_msg = format!(
+                    "environment variable `{}` for field `{}` could not be parsed",
+                    env.value(),
+                    fd.name()
+                );
+
+                quote! {
+                    match #value {
+                        #core::option::Option::Some(value) => #core::option::Option::Some(value),
+                        #core::option::Option::None => match ::std::env::var(#env) {
+                            #core::result::Result::Ok(var) => #core::option::Option::Some(
+                                <#ty as ::std::str::FromStr>::from_str(&var)
+                                    .map_err(|_| <#builder_err_ty>::new(#err_field, #err_msg))?,
+                            ),
+                            #core::result::Result::Err(_) => #core::option::Option::None,
+                        },
+                    }
+                }
+            } else {
+                value
+            };
+
+            match &fd.kind {
+                _ if target.is_state_field(fd) => {
+                    quote! {
+                        #member: self.#ident,
+                    }
+                }
+                TypeKind::Optional => {
+                    if let (Some(FieldDefault::Expr(default)), true) = (&fd.default, is_const) {
+                        quote! {
+                            #member: match #value {
+                                value @ #core::option::Option::Some(_) => value,
+                                #core::option::Option::None => #default,
+                            },
+                        }
+                    } else if let Some(FieldDefault::Expr(default)) = &fd.default {
+                        quote! {
+                            #member: #value.or_else(|| #default),
+                        }
+                    } else {
+                        quote! {
+                            #member: #value,
+                        }
+                    }
+                }
+                TypeKind::Repeated { .. } => {
+                    quote! {
+                        #member: #value,
+                    }
+                }
+                TypeKind::Computed {
+                    default: Some(default),
+                } => {
+                    quote! {
+                        #member: #default,
+                    }
+                }
+                TypeKind::Computed { default: None } => {
+                    quote! {
+                        #member: #core::default::Default::default(),
+                    }
+                }
+                TypeKind::SubBuilder { .. } => {
+                    let builder = if by_ref {
+                        quote! { #core::clone::Clone::clone(&self.#ident) }
+                    } else {
+                        quote! { self.#ident }
+                    };
+
+                    let err_msg_prefix = format!("field `{}` could not be built: ", fd.name());
+
+                    quote! {
+                        #member: #builder.__derive_builder_build().map_err(|err| {
+                            <#builder_err_ty>::nested(#err_field, #err_msg_prefix, err)
+                        })?,
+                    }
+                }
+                TypeKind::Custom { .. } => {
+                    let build_ident = get_custom_field_build_ident(fd);
+                    let builder_ty_field_assignments = target
+                        .fields_data
+                        .iter()
+                        .zip(get_builder_ty_field_assignments(target))
+                        .map(|(other_fd, assignment)| {
+                            if other_fd.ident == *ident {
+                                quote! { #ident: #value, }
+                            } else {
+                                assignment.into_token_stream()
+                            }
+                        });
+
+                    quote! {
+                        #member: Self {
+                            #(#builder_ty_field_assignments)*
+                        }.#build_ident(),
+                    }
+                }
+                TypeKind::Regular => {
+                    if let Some(FieldDefault::Trait) = &fd.default {
+                        quote! {
+                            #member: #value.unwrap_or_default(),
+                        }
+                    } else if let (Some(FieldDefault::Expr(default)), true) =
+                        (&fd.default, is_const)
+                    {
+                        quote! {
+                            #member: match #value {
+                                #core::option::Option::Some(value) => value,
+                                #core::option::Option::None => #default,
+                            },
+                        }
+                    } else if let Some(FieldDefault::Expr(default)) = &fd.default {
+                        quote! {
+                            #member: #value.unwrap_or_else(|| #default),
+                        }
+                    } else if is_const {
+                        let err_msg = format!("field `{}` was not set", fd.name());
+
+                        quote! {
+                            #member: match #value {
+                                #core::option::Option::Some(value) => value,
+                                #core::option::Option::None => #core::panic!(#err_msg),
+                            },
+                        }
+                    } else {
+                        let err_msg = format!("field `{}` was not set", fd.name());
+
+                        quote! {
+                            #member: #value.ok_or(<#builder_err_ty>::new(#err_field, #err_msg))?,
+                        }
+                    }
+                }
+            }
+        })
+}
+
+fn get_missing_fields_check(target: &BuilderTarget<'_>) -> TokenStream2 {
+    let core = target.struct_attrs.core_path();
+    let alloc = target.struct_attrs.alloc_path();
+    let builder_err_ty = target.builder_err_ty;
+    let builder_err_field_ty = target.builder_err_field_ty;
+
+    let missing_field_checks = target
+        .fields_data_with_err_field_variants()
+        .filter(|(fd, _)| fd.is_required())
+        .map(|(fd, err_field_variant)| {
+            let ident = &fd.ident;
+            let name = fd.name();
+
+            let is_missing = if let Some(env) = &fd.env {
+                quote! { self.#ident.is_none() && ::std::env::var_os(#env).is_none() }
+            } else {
+                quote! { self.#ident.is_none() }
+            };
+
+            quote! {
+                if #is_missing {
+                    missing_fields.push((#builder_err_field_ty::#err_field_variant, #name));
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if missing_field_checks.is_empty() {
+        return quote! {};
+    }
+
+    quote! {
+        let mut missing_fields = #alloc::vec::Vec::new();
+        #(#missing_field_checks)*
+
+        if !missing_fields.is_empty() {
+            #core::result::Result::<(), #builder_err_ty>::Err(
+                <#builder_err_ty>::missing(&missing_fields),
+            )?;
+        }
+    }
+}
+
+fn get_builder_ty_field_assignments<'a>(
+    target: &'a BuilderTarget<'_>,
+) -> impl Iterator<Item = impl ToTokens> + 'a {
+    target.fields_data.iter().map(|fd| {
+        let ident = &fd.ident;
+
+        if target.is_state_field(fd) {
+            quote! {
+                #ident: (),
+            }
+        } else if let Some(value) = get_builder_field_unset_value(fd, target.struct_attrs) {
+            quote! {
+                #ident: #value,
+            }
+        } else {
+            quote! {}
+        }
+    })
+}
+
+fn get_builder_field_unset_value(
+    fd: &FieldData<'_>,
+    struct_attrs: &StructAttrs,
+) -> Option<TokenStream2> {
+    let core = struct_attrs.core_path();
+    let alloc = struct_attrs.alloc_path();
+
+    match fd.kind {
+        TypeKind::Repeated {
+            collection: Collection::Vec,
+            ..
+        } => Some(quote! { #alloc::vec::Vec::new() }),
+        TypeKind::Repeated {
+            collection: Collection::Map { .. },
+            ..
+        }
+        | TypeKind::SubBuilder { .. }
+        | TypeKind::Custom { .. } => Some(quote! { #core::default::Default::default() }),
+        TypeKind::Computed { .. } => None,
+        TypeKind::Regular | TypeKind::Optional => Some(quote! { #core::option::Option::None }),
+    }
+}
+
+fn get_build_method_body(
+    caller_ty: &Path,
+    caller_ty_field_assignments: impl Iterator<Item = impl ToTokens>,
+    struct_attrs: &StructAttrs,
+) -> impl ToTokens {
+    let value = if struct_attrs.remote.is_some() {
+        quote! {
+            #caller_ty::__derive_builder_into_remote(#caller_ty{
+                #(#caller_ty_field_assignments)*
+            })
+        }
+    } else {
+        quote! {
+            #caller_ty{
+                #(#caller_ty_field_assignments)*
+            }
+        }
+    };
+
+    if let Some(finalize) = &struct_attrs.finalize {
+        quote! {
+            {
+                let mut value = #value;
+
+                #finalize(&mut value);
+                value
+            }
+        }
+    } else {
+        value
+    }
+}
+
+enum Collection<'a> {
+    Vec,
+    Map { key_ty: &'a Type, map_ty: &'a Type },
+}
+
+enum TypeKind<'a> {
+    Regular,
+    Optional,
+    Repeated {
+        ident: Ident,
+        collection: Collection<'a>,
+    },
+    Computed {
+        default: Option<Expr>,
+    },
+    SubBuilder {
+        builder_ty: Type,
+    },
+    Custom {
+        builder_field_ty: Type,
+        build: Expr,
+    },
+}
+
+#[derive(Default)]
+struct StructAttrs {
+    name: Option<Ident>,
+    vis: Option<Visibility>,
+    derive: Option<Vec<Path>>,
+    finalize: Option<Path>,
+    typestate: Option<Path>,
+    serde: Option<Path>,
+    mutators: Option<MutatorsAttr>,
+    no_std: Option<Path>,
+    constant: Option<Path>,
+    rename_all: Option<RenameRule>,
+    default_from: Option<DefaultFromAttr>,
+    constructor: Option<Path>,
+    remote: Option<RemoteAttr>,
+    setter: StructSetterAttrs,
+    build_fn: BuildFnAttrs,
+}
+
+impl StructAttrs {
+    fn check_compatibility(&self) -> Result<(), Error> {
+        if let (Some(owned), Some(_)) = (&self.setter.owned, &self.setter.mutable) {
+            return Err(get_incompatible_keys_err(owned, "mutable"));
+        }
+
+        if let Some(typestate) = &self.typestate {
+            if self.setter.mutable.is_some() {
+                return Err(get_incompatible_keys_err(typestate, "mutable"));
+            }
+
+            if self.build_fn.error.is_some() {
+                return Err(get_incompatible_keys_err(typestate, "error"));
+            }
+
+            if self.build_fn.validate.is_some() {
+                return Err(get_incompatible_keys_err(typestate, "validate"));
+            }
+
+            if self.build_fn.by_ref.is_some() {
+                return Err(get_incompatible_keys_err(typestate, "by_ref"));
+            }
+
+            if self.serde.is_some() {
+                return Err(get_incompatible_keys_err(typestate, "serde"));
+            }
+        }
+
+        if let Some(constant) = &self.constant {
+            if self.build_fn.error.is_some() {
+                return Err(get_incompatible_keys_err(constant, "error"));
+            }
+
+            if self.build_fn.validate.is_some() {
+                return Err(get_incompatible_keys_err(constant, "validate"));
+            }
+
+            if self.build_fn.by_ref.is_some() {
+                return Err(get_incompatible_keys_err(constant, "by_ref"));
+            }
+
+            if self.build_fn.wrap.is_some() {
+                return Err(get_incompatible_keys_err(constant, "wrap"));
+            }
+        }
+
+        if let Some(constructor) = &self.constructor {
+            if self.build_fn.error.is_some() {
+                return Err(get_incompatible_keys_err(constructor, "error"));
+            }
+
+            if self.build_fn.validate.is_some() {
+                return Err(get_incompatible_keys_err(constructor, "validate"));
+            }
+
+            if self.build_fn.wrap.is_some() {
+                return Err(get_incompatible_keys_err(constructor, "wrap"));
+            }
+        }
+
+        if let (Some(remote), Some(_)) = (&self.remote, &self.constructor) {
+            return Err(get_incompatible_keys_err(&remote.key, "constructor"));
+        }
+
+        Ok(())
+    }
+
+    fn builder_derive_attr(&self) -> Option<TokenStream2> {
+        self.derive
+            .as_ref()
+            .map(|derives| quote! { #[derive(#(#derives),*)] })
+    }
+
+    fn mutator_fns(&self) -> &[ImplItemFn] {
+        self.mutators
+            .as_ref()
+            .map_or(&[], |mutators| mutators.fns.as_slice())
+    }
+
+    fn method_lint_attrs(&self) -> Option<TokenStream2> {
+        match self.rename_all {
+            None | Some(RenameRule::Lower | RenameRule::Snake) => None,
+            Some(_) => Some(quote! { #[allow(non_snake_case)] }),
+        }
+    }
+
+    fn constness(&self) -> Option<TokenStream2> {
+        self.constant.as_ref().map(|_| quote! { const })
+    }
+
+    fn core_path(&self) -> TokenStream2 {
+        if self.no_std.is_some() {
+            quote! { ::core }
+        } else {
+            quote! { ::std }
+        }
+    }
+
+    fn alloc_path(&self) -> TokenStream2 {
+        if self.no_std.is_some() {
+            quote! { ::alloc }
+        } else {
+            quote! { ::std }
+        }
+    }
+
+    fn setter_mode(&self) -> SetterMode {
+        if self.setter.owned.is_some() {
+            SetterMode::Owned
+        } else {
+            SetterMode::Mutable
+        }
+    }
+
+    fn build_fn_ident(&self) -> Ident {
+        self.build_fn
+            .name
+            .clone()
+            .unwrap_or_else(|| Ident::new("build", Span::call_site()))
+    }
+}
+
+struct MutatorsAttr {
+    key: Path,
+    fns: Vec<ImplItemFn>,
+}
+
+struct DefaultFromAttr {
+    key: Path,
+    template: Path,
+}
+
+struct RemoteAttr {
+    key: Path,
+    ty: Path,
+}
+
+#[derive(Clone, Copy)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+}
+
+impl RenameRule {
+    const ALL: [(&'static str, RenameRule); 6] = [
+        ("lowercase", RenameRule::Lower),
+        ("UPPERCASE", RenameRule::Upper),
+        ("PascalCase", RenameRule::Pascal),
+        ("camelCase", RenameRule::Camel),
+        ("snake_case", RenameRule::Snake),
+        ("SCREAMING_SNAKE_CASE", RenameRule::ScreamingSnake),
+    ];
+
+    fn from_lit(lit: &LitStr) -> Result<RenameRule, Error> {
+        let value = lit.value();
+
+        RenameRule::ALL
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map(|(_, rule)| *rule)
+            .ok_or_else(|| {
+                let names = RenameRule::ALL
+                    .iter()
+                    .map(|(name, _)| format!("`{name}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                Error::new_spanned(
+                    lit,
+                    format!("unknown naming convention `{value}`, expected one of {names}"),
+                )
+            })
+    }
+
+    fn apply(self, name: &str) -> String {
+        match self {
+            RenameRule::Lower | RenameRule::Snake => name.to_owned(),
+            RenameRule::Upper | RenameRule::ScreamingSnake => name.to_uppercase(),
+            RenameRule::Pascal => to_upper_camel_case(name),
+            RenameRule::Camel => {
+                let pascal_case = to_upper_camel_case(name);
+                let mut chars = pascal_case.chars();
+
+                chars
+                    .next()
+                    .into_iter()
+                    .flat_map(char::to_lowercase)
+                    .chain(chars)
+                    .collect()
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct StructSetterAttrs {
+    prefix: Option<String>,
+    owned: Option<Path>,
+    mutable: Option<Path>,
+}
+
+#[derive(Default)]
+struct BuildFnAttrs {
+    name: Option<Ident>,
+    error: Option<Type>,
+    validate: Option<Path>,
+    by_ref: Option<Path>,
+    wrap: Option<Path>,
+}
+
+#[derive(Clone, Copy)]
+enum SetterMode {
+    Mutable,
+    Owned,
+}
+
+impl SetterMode {
+    fn receiver(self) -> TokenStream2 {
+        match self {
+            SetterMode::Mutable => quote! { &mut self },
+            SetterMode::Owned => quote! { mut self },
+        }
+    }
+
+    fn return_ty(self) -> TokenStream2 {
+        match self {
+            SetterMode::Mutable => quote! { &mut Self },
+            SetterMode::Owned => quote! { Self },
+        }
+    }
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    each: Option<Ident>,
+    computed: Option<ComputedAttr>,
+    default: Option<DefaultAttr>,
+    required: Option<Path>,
+    env: Option<EnvAttr>,
+    sub_builder: Option<Path>,
+    field: Option<CustomFieldAttr>,
+    setter: SetterAttrs,
+}
+
+impl FieldAttrs {
+    fn check_compatibility(&self) -> Result<(), Error> {
+        if let Some(computed) = &self.computed {
+            let computed_key_name = computed.key_name();
+
+            if self.each.is_some() {
+                return Err(get_incompatible_keys_err(&computed.key, "each"));
+            }
+
+            if self.default.is_some() {
+                return Err(get_incompatible_keys_err(&computed.key, "default"));
+            }
+
+            if self.env.is_some() {
+                return Err(get_incompatible_keys_err(&computed.key, "env"));
+            }
+
+            if self.required.is_some() {
+                return Err(get_incompatible_keys_err(&computed.key, "required"));
+            }
+
+            if self.sub_builder.is_some() {
+                return Err(get_incompatible_keys_err(&computed.key, "sub_builder"));
+            }
+
+            if self.setter.name.is_some() {
+                return Err(get_incompatible_keys_err(&computed.key, "name"));
+            }
+
+            if self.field.is_some() {
+                return Err(get_incompatible_keys_err(&computed.key, "field"));
+            }
+
+            if let Some(into) = &self.setter.into {
+                return Err(get_incompatible_keys_err(into, &computed_key_name));
+            }
+
+            if let Some(strip_option) = &self.setter.strip_option {
+                return Err(get_incompatible_keys_err(strip_option, &computed_key_name));
+            }
+
+            if let Some(try_into) = &self.setter.try_into {
+                return Err(get_incompatible_keys_err(try_into, &computed_key_name));
+            }
+        }
+
+        if let (Some(default), Some(_)) = (&self.default, &self.each) {
+            return Err(get_incompatible_keys_err(&default.key, "each"));
+        }
+
+        if let Some(sub_builder) = &self.sub_builder {
+            let other_keys = [
+                (self.each.is_some(), "each"),
+                (self.default.is_some(), "default"),
+                (self.required.is_some(), "required"),
+                (self.env.is_some(), "env"),
+                (self.setter.name.is_some(), "name"),
+                (self.setter.into.is_some(), "into"),
+                (self.setter.strip_option.is_some(), "strip_option"),
+                (self.setter.try_into.is_some(), "try_into"),
+            ];
+
+            if let Some((_, other_key)) = other_keys.iter().find(|(is_present, _)| *is_present) {
+                return Err(get_incompatible_keys_err(sub_builder, other_key));
+            }
+        }
+
+        if let Some(field) = &self.field {
+            let other_keys = [
+                (self.each.is_some(), "each"),
+                (self.default.is_some(), "default"),
+                (self.required.is_some(), "required"),
+                (self.env.is_some(), "env"),
+                (self.sub_builder.is_some(), "sub_builder"),
+                (self.setter.strip_option.is_some(), "strip_option"),
+                (self.setter.try_into.is_some(), "try_into"),
+            ];
+
+            if let Some((_, other_key)) = other_keys.iter().find(|(is_present, _)| *is_present) {
+                return Err(get_incompatible_keys_err(&field.key, other_key));
+            }
+        }
+
+        if let Some(required) = &self.required {
+            if self.each.is_some() {
+                return Err(get_incompatible_keys_err(required, "each"));
+            }
+
+            if self.default.is_some() {
+                return Err(get_incompatible_keys_err(required, "default"));
+            }
+
+            if self.env.is_some() {
+                return Err(get_incompatible_keys_err(required, "env"));
+            }
+
+            if self.setter.strip_option.is_some() {
+                return Err(get_incompatible_keys_err(required, "strip_option"));
+            }
+        }
+
+        if let (Some(env), Some(_)) = (&self.env, &self.each) {
+            return Err(get_incompatible_keys_err(&env.key, "each"));
+        }
+
+        if let (Some(strip_option), Some(_)) = (&self.setter.strip_option, &self.each) {
+            return Err(get_incompatible_keys_err(strip_option, "each"));
+        }
+
+        if let (Some(append_only), None) = (&self.setter.append_only, &self.each) {
+            return Err(Error::new_spanned(
+                append_only,
+                "`append_only` requires a `builder(each = \"...\")` attribute on the field",
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn check_const_compatibility(&self) -> Result<(), Error> {
+        if let Some(each) = &self.each {
+            return Err(Error::new_spanned(
+                each,
+                "`each` cannot be used together with `const`",
+            ));
+        }
+
+        if let Some(DefaultAttr {
+            key,
+            value: FieldDefault::Trait,
+        }) = &self.default
+        {
+            return Err(Error::new_spanned(
+                key,
+                "`default` requires an expression when used together with `const`",
+            ));
+        }
+
+        if let Some(ComputedAttr { key, default: None }) = &self.computed {
+            return Err(Error::new_spanned(
+                key,
+                format!(
+                    "`{}` requires an expression when used together with `const`",
+                    key.to_token_stream()
+                ),
+            ));
+        }
+
+        let other_keys = [
+            self.env.as_ref().map(|env| &env.key),
+            self.sub_builder.as_ref(),
+            self.field.as_ref().map(|field| &field.key),
+            self.setter.into.as_ref(),
+            self.setter.try_into.as_ref(),
+        ];
+
+        if let Some(key) = other_keys.into_iter().flatten().next() {
+            return Err(get_incompatible_keys_err(key, "const"));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct SetterAttrs {
+    name: Option<Ident>,
+    into: Option<Path>,
+    strip_option: Option<Path>,
+    try_into: Option<Path>,
+    append_only: Option<Path>,
+}
+
+struct ComputedAttr {
+    key: Path,
+    default: Option<Expr>,
+}
+
+impl ComputedAttr {
+    fn key_name(&self) -> String {
+        self.key.to_token_stream().to_string()
+    }
+
+    fn requires_finalize_hook(&self) -> bool {
+        self.key.is_ident("computed")
+    }
+}
+
+struct CustomFieldAttr {
+    key: Path,
+    ty: Type,
+    build: Expr,
+}
+
+struct EnvAttr {
+    key: Path,
+    var: LitStr,
+}
+
+struct DefaultAttr {
+    key: Path,
+    value: FieldDefault,
+}
+
+enum FieldDefault {
+    Trait,
+    Expr(Expr),
+}
+
+struct BuilderTarget<'a> {
+    vis: &'a Visibility,
+    caller_ty: &'a Path,
+    path: Path,
+    builder_ty: Ident,
+    builder_err_ty: &'a Ident,
+    builder_err_field_ty: &'a Ident,
+    err_field_variant_idents: Vec<Ident>,
+    fields_data: Vec<FieldData<'a>>,
+    struct_attrs: &'a StructAttrs,
+}
+
+impl BuilderTarget<'_> {
+    fn is_state_field(&self, fd: &FieldData<'_>) -> bool {
+        self.struct_attrs.typestate.is_some() && fd.is_required()
+    }
+
+    fn has_infallible_build(&self) -> bool {
+        self.struct_attrs.build_fn.error.is_none()
+            && self.struct_attrs.build_fn.validate.is_none()
+            && !self
+                .fields_data
+                .iter()
+                .any(|fd| fd.is_required() || fd.env.is_some() || fd.is_sub_builder())
+    }
+
+    fn has_method(&self, name: &str) -> bool {
+        self.fields_data.iter().any(|fd| {
+            let each_ident = match &fd.kind {
+                TypeKind::Repeated { ident, .. } => Some(ident),
+                _ => None,
+            };
+
+            (!fd.is_computed() && fd.setter_ident == name)
+                || each_ident.is_some_and(|ident| ident == name)
+        }) || self
+            .struct_attrs
+            .mutator_fns()
+            .iter()
+            .any(|mutator| mutator.sig.ident == name)
+    }
+
+    fn fields_data_with_err_field_variants(
+        &self,
+    ) -> impl Iterator<Item = (&FieldData<'_>, &Ident)> {
+        self.fields_data.iter().zip(&self.err_field_variant_idents)
+    }
+
+    fn err_field_variants(&self) -> Vec<TokenStream2> {
+        let name = self.name();
+
+        self.fields_data_with_err_field_variants()
+            .filter(|(fd, _)| !fd.is_computed())
+            .map(|(fd, variant)| {
+                let doc_string = format!("The `{}` field of `{name}`.", fd.name());
+
+                quote! {
+                    #[doc = #doc_string]
+                    #variant,
+                }
+            })
+            .collect()
+    }
+
+    fn name(&self) -> String {
+        // The values of remote types are assembled as their local mirror.
+        if self.struct_attrs.remote.is_some() {
+            get_path_name(self.caller_ty)
+        } else {
+            get_path_name(&self.path)
+        }
+    }
+}
+
+struct FieldData<'a> {
+    index: usize,
+    member: Member,
+    ident: Ident,
+    setter_name: String,
+    setter_ident: Ident,
+    ty: &'a Type,
+    kind: TypeKind<'a>,
+    default: Option<FieldDefault>,
+    env: Option<LitStr>,
+    setter: SetterAttrs,
+    docs: Vec<&'a Attribute>,
+}
+
+impl FieldData<'_> {
+    fn is_required(&self) -> bool {
+        matches!(self.kind, TypeKind::Regular) && self.default.is_none()
+    }
+
+    fn doc_attrs(&self) -> TokenStream2 {
+        if self.docs.is_empty() {
+            quote! {}
+        } else {
+            let docs = &self.docs;
+
+            quote! {
+                #(#docs)*
+                #[doc = ""]
+            }
+        }
+    }
+
+    fn is_computed(&self) -> bool {
+        matches!(self.kind, TypeKind::Computed { .. })
+    }
+
+    fn is_sub_builder(&self) -> bool {
+        matches!(self.kind, TypeKind::SubBuilder { .. })
+    }
+
+    fn name(&self) -> String {
+        match &self.member {
+            Member::Named(ident) => ident.unraw().to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        }
+    }
+}
+
+struct FieldToFieldData {
+    has_finalize_hook: bool,
+    is_typestate: bool,
+    is_no_std: bool,
+    is_const: bool,
+    has_constructor: bool,
+    setter_prefix: Option<String>,
+    setter_rename_rule: Option<RenameRule>,
+    default_from: Option<Path>,
+    repeated_builder_method_raw_idents: HashSet<String>,
+}
+
+impl FieldToFieldData {
+    fn new(struct_attrs: &StructAttrs) -> FieldToFieldData {
+        FieldToFieldData {
+            has_finalize_hook: struct_attrs.finalize.is_some(),
+            is_typestate: struct_attrs.typestate.is_some(),
+            is_no_std: struct_attrs.no_std.is_some(),
+            is_const: struct_attrs.constant.is_some(),
+            has_constructor: struct_attrs.constructor.is_some(),
+            setter_prefix: struct_attrs.setter.prefix.clone(),
+            setter_rename_rule: struct_attrs.rename_all,
+            default_from: struct_attrs
+                .default_from
+                .as_ref()
+                .map(|default_from| default_from.template.clone()),
+            repeated_builder_method_raw_idents: HashSet::new(),
+        }
+    }
+
+    fn convert<'a>(&mut self, index: usize, field: &'a Field) -> Result<FieldData<'a>, Error> {
+        let mut field_attrs = get_field_attrs(field)?;
+        field_attrs.check_compatibility()?;
+
+        if self.is_const {
+            field_attrs.check_const_compatibility()?;
+        }
+
+        let mut default = field_attrs.default.take().map(|default| default.value);
+        let is_marked_required = field_attrs.required.is_some();
+        let env = field_attrs.env.take();
+
+        if let (Some(env), true) = (&env, self.is_typestate) {
+            return Err(get_incompatible_keys_err(&env.key, "typestate"));
+        }
+
+        if let (Some(env), true) = (&env, self.is_no_std) {
+            return Err(get_incompatible_keys_err(&env.key, "no_std"));
+        }
+
+        if let (Some(env), true) = (&env, self.has_constructor) {
+            return Err(get_incompatible_keys_err(&env.key, "constructor"));
+        }
+
+        if let (Some(sub_builder), true) = (&field_attrs.sub_builder, self.has_constructor) {
+            return Err(get_incompatible_keys_err(sub_builder, "constructor"));
+        }
+
+        let setter = std::mem::take(&mut field_attrs.setter);
+        let (ty, kind) = self.get_ty_and_kind(field, field_attrs)?;
+
+        if let (Some(_), TypeKind::Regular) = (&setter.strip_option, &kind) {
+            return Err(Error::new_spanned(&field.ty, "expected standard Option"));
+        }
+
+        let (member, ident) = if let Some(ident) = &field.ident {
+            (Member::Named(ident.clone()), ident.clone())
+        } else {
+            (
+                Member::Unnamed(Index {
+                    index: u32::try_from(index).map_err(|_| {
+                        Error::new_spanned(field, "too many fields in tuple-struct")
+                    })?,
+                    span: field.ty.span(),
+                }),
+                format_ident!("field_{}", index),
+            )
+        };
+
+        if let (None, Some(template), false, TypeKind::Regular | TypeKind::Optional) =
+            (&default, &self.default_from, is_marked_required, &kind)
+        {
+            default = Some(FieldDefault::Expr(if self.is_const {
+                parse_quote!(#template.#member)
+            } else if self.is_no_std {
+                parse_quote!(::core::clone::Clone::clone(&#template.#member))
+            } else {
+                parse_quote!(::std::clone::Clone::clone(&#template.#member))
+            }));
+        }
+
+        let (setter_name, setter_ident) = if let Some(name) = &setter.name {
+            (name.unraw().to_string(), name.clone())
+        } else {
+            let name = format!(
+                "{}{}",
+                self.setter_prefix.as_deref().unwrap_or_default(),
+                ident.unraw()
+            );
+            let setter_ident = get_method_ident(self.setter_rename_rule, &name, ident.span());
+
+            (name, setter_ident)
+        };
+
+        Ok(FieldData {
+            index,
+            member,
+            ident,
+            setter_name,
+            setter_ident,
+            ty,
+            kind,
+            default,
+            env: env.map(|env| env.var),
+            setter,
+            docs: field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("doc"))
+                .collect(),
+        })
+    }
+
+    fn get_ty_and_kind<'a>(
+        &mut self,
+        field: &'a Field,
+        field_attrs: FieldAttrs,
+    ) -> Result<(&'a Type, TypeKind<'a>), Error> {
+        if let Some(computed) = field_attrs.computed {
+            if self.has_finalize_hook || !computed.requires_finalize_hook() {
+                Ok((
+                    &field.ty,
+                    TypeKind::Computed {
+                        default: computed.default,
+                    },
+                ))
+            } else {
+                Err(Error::new_spanned(
+                    computed.key,
+                    "`computed` fields require a `builder(finalize = \"...\")` attribute on the struct",
+                ))
+            }
+        } else if let Some(ident) = field_attrs.each {
+            if self
+                .repeated_builder_method_raw_idents
+                .insert(ident.unraw().to_string())
+            {
+                if let Some(ty) = get_concrete_ty_from_vec_ty(&field.ty) {
+                    Ok((
+                        ty,
+                        TypeKind::Repeated {
+                            ident,
+                            collection: Collection::Vec,
+                        },
+                    ))
+                } else if let Some((key_ty, value_ty)) = get_concrete_tys_from_map_ty(&field.ty) {
+                    Ok((
+                        value_ty,
+                        TypeKind::Repeated {
+                            ident,
+                            collection: Collection::Map {
+                                key_ty,
+                                map_ty: &field.ty,
+                            },
+                        },
+                    ))
+                } else {
+                    Err(Error::new_spanned(
+                        &field.ty,
+                        "expected standard Vec, HashMap or BTreeMap",
+                    ))
+                }
+            } else {
+                Err(Error::new(
+                    ident.span(),
+                    format!("identifier `{}` is duplicated", ident.unraw()),
+                ))
+            }
+        } else if let Some(field_attr) = field_attrs.field {
+            if self.is_typestate {
+                return Err(get_incompatible_keys_err(&field_attr.key, "typestate"));
+            }
+
+            Ok((
+                &field.ty,
+                TypeKind::Custom {
+                    builder_field_ty: field_attr.ty,
+                    build: field_attr.build,
+                },
+            ))
+        } else if let Some(sub_builder) = field_attrs.sub_builder {
+            if self.is_typestate {
+                return Err(get_incompatible_keys_err(&sub_builder, "typestate"));
+            }
+
+            let builder_ty = get_sub_builder_ty(&field.ty).ok_or(Error::new_spanned(
+                &field.ty,
+                "expected a path to a struct that derives `Builder`",
+            ))?;
+
+            Ok((&field.ty, TypeKind::SubBuilder { builder_ty }))
+        } else if field_attrs.required.is_some() {
+            Ok((&field.ty, TypeKind::Regular))
+        } else if is_phantom_data_ty(&field.ty)
+            && !field
+                .attrs
+                .iter()
+                .any(|attr| is_builder_attribute(attr.path()))
+        {
+            let default = if self.is_no_std {
+                parse_quote!(::core::marker::PhantomData)
+            } else {
+                parse_quote!(::std::marker::PhantomData)
+            };
+
+            Ok((
+                &field.ty,
+                TypeKind::Computed {
+                    default: Some(default),
+                },
+            ))
+        } else if let Some(ty) = get_concrete_ty_from_option_ty(&field.ty) {
+            Ok((ty, TypeKind::Optional))
+        } else {
+            Ok((&field.ty, TypeKind::Regular))
+        }
+    }
+}
+
+fn get_field_attrs(field: &Field) -> Result<FieldAttrs, Error> {
+    let mut field_attrs = FieldAttrs::default();
+
+    if let Some(attr) = get_builder_attr(
+        &field.attrs,
+        "multiple `builder` attributes on the same field",
+    )? {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("each") {
+                check_key_is_not_duplicated(&meta, field_attrs.each.is_some())?;
+                field_attrs.each = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("default") {
+                check_key_is_not_duplicated(&meta, field_attrs.default.is_some())?;
+
+                let value = if meta.input.peek(Token![=]) {
+                    FieldDefault::Expr(meta.value()?.parse::<LitStr>()?.parse()?)
+                } else {
+                    FieldDefault::Trait
+                };
+
+                field_attrs.default = Some(DefaultAttr {
+                    key: meta.path.clone(),
+                    value,
+                });
+
+                Ok(())
+            } else if meta.path.is_ident("setter") {
+                meta.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("name") {
+                        check_key_is_not_duplicated(&meta, field_attrs.setter.name.is_some())?;
+                        field_attrs.setter.name = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("into") {
+                        check_key_is_not_duplicated(&meta, field_attrs.setter.into.is_some())?;
+                        field_attrs.setter.into = Some(meta.path);
+                        Ok(())
+                    } else if meta.path.is_ident("strip_option") {
+                        check_key_is_not_duplicated(
+                            &meta,
+                            field_attrs.setter.strip_option.is_some(),
+                        )?;
+
+                        field_attrs.setter.strip_option = Some(meta.path);
+                        Ok(())
+                    } else if meta.path.is_ident("try_into") {
+                        check_key_is_not_duplicated(&meta, field_attrs.setter.try_into.is_some())?;
+                        field_attrs.setter.try_into = Some(meta.path);
+                        Ok(())
+                    } else if meta.path.is_ident("append_only") {
+                        check_key_is_not_duplicated(
+                            &meta,
+                            field_attrs.setter.append_only.is_some(),
+                        )?;
+
+                        field_attrs.setter.append_only = Some(meta.path);
+                        Ok(())
+                    } else {
+                        Err(get_unrecognized_key_err(&meta, FIELD_SETTER_KEYS))
+                    }
+                })
+            } else if meta.path.is_ident("field") {
+                check_key_is_not_duplicated(&meta, field_attrs.field.is_some())?;
+
+                let mut ty = None;
+                let mut build = None;
+
+                meta.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("ty") {
+                        check_key_is_not_duplicated(&meta, ty.is_some())?;
+                        ty = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("build") {
+                        check_key_is_not_duplicated(&meta, build.is_some())?;
+                        build = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                        Ok(())
+                    } else {
+                        Err(get_unrecognized_key_err(&meta, CUSTOM_FIELD_KEYS))
+                    }
+                })?;
+
+                if let (Some(ty), Some(build)) = (ty, build) {
+                    field_attrs.field = Some(CustomFieldAttr {
+                        key: meta.path,
+                        ty,
+                        build,
+                    });
+
+                    Ok(())
+                } else {
+                    Err(Error::new_spanned(
+                        meta.path,
+                        "expected `field(ty = \"...\", build = \"...\")`",
+                    ))
+                }
+            } else if meta.path.is_ident("sub_builder") {
+                check_key_is_not_duplicated(&meta, field_attrs.sub_builder.is_some())?;
+                field_attrs.sub_builder = Some(meta.path);
+                Ok(())
+            } else if meta.path.is_ident("required") {
+                check_key_is_not_duplicated(&meta, field_attrs.required.is_some())?;
+                field_attrs.required = Some(meta.path);
+                Ok(())
+            } else if meta.path.is_ident("env") {
+                check_key_is_not_duplicated(&meta, field_attrs.env.is_some())?;
+
+                field_attrs.env = Some(EnvAttr {
+                    key: meta.path.clone(),
+                    var: meta.value()?.parse()?,
+                });
+
+                Ok(())
+            } else if meta.path.is_ident("computed") || meta.path.is_ident("skip") {
+                if let Some(computed) = &field_attrs.computed {
+                    if !meta.path.is_ident(&computed.key_name()) {
+                        return Err(get_incompatible_keys_err(&meta.path, &computed.key_name()));
+                    }
+                }
+
+                check_key_is_not_duplicated(&meta, field_attrs.computed.is_some())?;
+
+                let default = if meta.input.peek(Token![=]) {
+                    Some(meta.value()?.parse::<LitStr>()?.parse()?)
+                } else {
+                    None
+                };
+
+                field_attrs.computed = Some(ComputedAttr {
+                    key: meta.path.clone(),
+                    default,
+                });
+
+                Ok(())
+            } else {
+                Err(get_unrecognized_key_err(&meta, FIELD_KEYS))
+            }
+        })?;
+    }
+
+    Ok(field_attrs)
+}
+
+fn get_builder_attr<'a>(
+    attrs: &'a [Attribute],
+    multiple_attr_err_msg: &str,
+) -> Result<Option<&'a Attribute>, Error> {
+    let invalid_attr_err_msg = "expected `builder(...)`";
+
+    let mut builder_attrs = attrs.iter().filter_map(|attr| match &attr.meta {
+        Meta::List(l) if is_builder_attribute(&l.path) => Some(Ok(attr)),
+        Meta::NameValue(nv) if is_builder_attribute(&nv.path) => Some(Err((
+            Error::new_spanned(&attr.meta, invalid_attr_err_msg),
+            attr,
+        ))),
+        Meta::Path(p) if is_builder_attribute(p) => {
+            Some(Err((Error::new(p.span(), invalid_attr_err_msg), attr)))
+        }
+        _ => None,
+    });
+
+    match (builder_attrs.next(), builder_attrs.next()) {
+        (None, None) => Ok(None),
+        (Some(Ok(attr)), None) => Ok(Some(attr)),
+        (Some(Err((e, _))), None) => Err(e),
+        (_, Some(Ok(attr) | Err((_, attr)))) => {
+            Err(Error::new_spanned(attr, multiple_attr_err_msg))
+        }
+    }
+}
+
+fn check_key_is_not_duplicated(
+    meta: &ParseNestedMeta<'_>,
+    is_duplicated: bool,
+) -> Result<(), Error> {
+    if is_duplicated {
+        Err(meta.error(format!(
+            "key `{}` is duplicated",
+            meta.path.to_token_stream()
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+fn get_err_field_variant_idents(
+    fields_data: &[FieldData<'_>],
+    variant: Option<&Ident>,
+    taken: &mut HashSet<String>,
+) -> Vec<Ident> {
+    let variant_name = variant.map(|variant| variant.unraw().to_string());
+
+    fields_data
+        .iter()
+        .enumerate()
+        .map(|(index, fd)| {
+            let mut name = format!(
+                "{}{}",
+                variant_name.as_deref().unwrap_or_default(),
+                to_upper_camel_case(&fd.ident.unraw().to_string())
+            );
+
+            // Field names such as `_0` or `__` have no letter to start an
+            // identifier with once their underscores are removed.
+            if name.chars().next().is_none_or(|c| c.is_ascii_digit()) {
+                name.insert_str(0, "Field");
+            }
+
+            // Distinct field names such as `a_1` and `a1` can map to the same
+            // variant, so the later ones are suffixed with their index.
+            let mut unique_name = name.clone();
+            let mut suffix = index;
+
+            while !taken.insert(unique_name.clone()) {
+                unique_name = format!("{name}{suffix}");
+                suffix += 1;
+            }
+
+            format_ident!("{}", unique_name, span = fd.ident.span())
+        })
+        .collect()
+}
+
+// Conservative: restricted visibilities are only compared when they are
+// `pub(crate)` or identical.
+fn is_at_least_as_visible(vis: &Visibility, other: &Visibility) -> bool {
+    match (vis, other) {
+        (Visibility::Public(_), _) | (_, Visibility::Inherited) => true,
+        (Visibility::Restricted(vis), Visibility::Restricted(other)) => {
+            vis.path.is_ident("crate")
+                || vis.path.to_token_stream().to_string()
+                    == other.path.to_token_stream().to_string()
+        }
+        _ => false,
+    }
+}
+
+fn get_path_name(path: &Path) -> String {
+    path.segments
+        .iter()
+        .map(|segment| segment.ident.unraw().to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+fn get_incompatible_keys_err(key: &Path, other_key: &str) -> Error {
+    Error::new_spanned(
+        key,
+        format!(
+            "`{}` cannot be used together with `{other_key}`",
+            key.to_token_stream()
+        ),
+    )
+}
+
+fn get_unrecognized_key_err(meta: &ParseNestedMeta<'_>, keys: &[(&str, &str)]) -> Error {
+    let key = meta.path.to_token_stream().to_string();
+    let mut err = Error::new_spanned(
+        &meta.path,
+        format!("unrecognized key `{key}` in `builder` attribute"),
+    );
+
+    let closest_key = keys
+        .iter()
+        .map(|(valid_key, _)| (edit_distance(&key, valid_key), valid_key))
+        .filter(|(distance, _)| *distance <= key.len().max(3) / 3)
+        .min_by_key(|(distance, _)| *distance);
+
+    let hint = if let Some((_, closest_key)) = closest_key {
+        format!("did you mean `{closest_key}`?")
+    } else {
+        let forms = keys
+            .iter()
+            .map(|(_, form)| *form)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!("expected one of {forms}")
+    };
+
+    err.combine(Error::new_spanned(&meta.path, hint));
+    err
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut distances = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut prev_diagonal = distances[0];
+        distances[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = prev_diagonal + usize::from(a_char != *b_char);
+            prev_diagonal = distances[j + 1];
+            distances[j + 1] = substitution.min(distances[j] + 1).min(prev_diagonal + 1);
+        }
+    }
+
+    distances[b.len()]
+}
+
+fn new_ident(name: &str, span: Span) -> Ident {
+    match syn::parse_str::<Ident>(name) {
+        Ok(mut ident) => {
+            ident.set_span(span);
+            ident
+        }
+        // These keywords cannot be raw identifiers.
+        Err(_) if matches!(name, "crate" | "self" | "super" | "Self") => {
+            Ident::new(&format!("{name}_"), span)
+        }
+        Err(_) => Ident::new_raw(name, span),
+    }
+}
+
+fn get_method_ident(rename_rule: Option<RenameRule>, name: &str, span: Span) -> Ident {
+    match rename_rule {
+        Some(rule) => new_ident(&rule.apply(name), span),
+        None => new_ident(name, span),
+    }
+}
+
+fn to_upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .into_iter()
+                .flat_map(char::to_uppercase)
+                .chain(chars)
+        })
+        .collect()
+}
+
+fn to_snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut snake_case = String::with_capacity(name.len());
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev = i.checked_sub(1).map(|i| chars[i]);
+            let next = chars.get(i + 1);
+
+            let starts_word = match prev {
+                Some(prev) if prev.is_lowercase() || prev.is_ascii_digit() => true,
+                Some(prev) if prev.is_uppercase() => next.is_some_and(|next| next.is_lowercase()),
+                _ => false,
+            };
+
+            if starts_word {
+                snake_case.push('_');
+            }
+
+            snake_case.extend(c.to_lowercase());
+        } else {
+            snake_case.push(c);
+        }
+    }
+
+    snake_case
+}
+
+fn is_builder_attribute(path: &Path) -> bool {
+    path.is_ident("builder")
+}
+
+fn get_sub_builder_ty(ty: &Type) -> Option<Type> {
+    if let Type::Path(path) = ty {
+        let mut builder_ty = path.clone();
+
+        match (&builder_ty.qself, builder_ty.path.segments.last_mut()) {
+            (None, Some(last_segment)) if last_segment.arguments.is_empty() => {
+                last_segment.ident = format_ident!("{}Builder", last_segment.ident.unraw());
+                Some(Type::Path(builder_ty))
+            }
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+fn get_concrete_ty_from_option_ty(ty: &Type) -> Option<&Type> {
+    get_concrete_ty(&["std", "option", "Option"], ty)
+        .or_else(|| get_concrete_ty(&["core", "option", "Option"], ty))
+}
+
+fn is_phantom_data_ty(ty: &Type) -> bool {
+    get_concrete_ty(&["std", "marker", "PhantomData"], ty)
+        .or_else(|| get_concrete_ty(&["core", "marker", "PhantomData"], ty))
+        .is_some()
+}
+
+fn get_concrete_ty_from_vec_ty(ty: &Type) -> Option<&Type> {
+    get_concrete_ty(&["std", "vec", "Vec"], ty)
+        .or_else(|| get_concrete_ty(&["alloc", "vec", "Vec"], ty))
+}
+
+fn get_concrete_tys_from_map_ty(ty: &Type) -> Option<(&Type, &Type)> {
+    [
+        ["std", "collections", "HashMap"],
+        ["std", "collections", "BTreeMap"],
+        ["alloc", "collections", "BTreeMap"],
+    ]
+    .iter()
+    .find_map(|raw_path| match get_generic_tys(raw_path, ty)?[..] {
+        [key_ty, value_ty] => Some((key_ty, value_ty)),
+        _ => None,
+    })
+}
+
+fn get_concrete_ty<'a>(raw_path: &[&str], ty: &'a Type) -> Option<&'a Type> {
+    match get_generic_tys(raw_path, ty)?[..] {
+        [ty] => Some(ty),
+        _ => None,
+    }
+}
+
+fn get_generic_tys<'a>(raw_path: &[&str], ty: &'a Type) -> Option<Vec<&'a Type>> {
+    Some(ty)
+        .and_then(|ty| {
+            if let Type::Path(path) = ty {
+                Some(path)
+            } else {
+                None
+            }
+        })
+        .and_then(|path| {
+            if path.qself.is_none() {
+                match path.path.leading_colon {
+                    Some(_) if path.path.segments.len() == raw_path.len() => {
+                        Some(&path.path.segments)
+                    }
+                    None if path.path.segments.len() <= raw_path.len() => Some(&path.path.segments),
+                    _ => None,
+                }
+            } else {
+                None
+            }
+        })
+        .and_then(|segments| {
+            let last_raw_segment = raw_path.last();
+            let last_segment = segments.last();
+
+            let valid_segment_count = raw_path
+                .iter()
+                .rev()
+                .skip(1)
+                .zip(segments.iter().rev().skip(1))
+                .take_while(|(raw_segment, segment)| {
+                    segment.ident == raw_segment && segment.arguments.is_empty()
+                })
+                .count();
+
+            match (last_raw_segment, last_segment) {
+                (Some(last_raw_segment), Some(last_segment))
+                    if valid_segment_count == segments.len() - 1 =>
+                {
+                    Some((last_raw_segment, last_segment))
+                }
+                _ => None,
+            }
+        })
+        .and_then(
+            |(last_raw_segment, last_segment)| match &last_segment.arguments {
+                PathArguments::AngleBracketed(args) if last_segment.ident == last_raw_segment => {
+                    Some(args.args.iter())
+                }
+                _ => None,
+            },
+        )
+        .and_then(|args| {
+            args.map(|arg| {
+                if let GenericArgument::Type(ty) = arg {
+                    Some(ty)
+                } else {
+                    None
+                }
+            })
+            .collect()
+        })
+}
@@ -0,0 +1,13 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(constructor, build_fn(validate = "check"))]
+pub struct Command {
+    executable: String,
+}
+
+fn check(_: &Command) -> Result<(), String> {
+    Ok(())
+}
+
+fn main() {}
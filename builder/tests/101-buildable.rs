@@ -0,0 +1,64 @@
+// Every type that derives Builder implements the Buildable trait, so generic
+// code can construct any of them through their builder.
+
+use derive_builder::{Buildable, Builder};
+
+#[derive(Builder, Debug, PartialEq)]
+pub struct Command {
+    executable: Option<String>,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(typestate)]
+pub struct Point {
+    x: Option<i32>,
+}
+
+// The builder is as visible as the struct, so the trait is implemented even
+// though the visibility of the builder is changed.
+#[derive(Builder, Debug, PartialEq)]
+#[builder(vis = "pub(crate)")]
+pub(crate) struct Job {
+    id: Option<u32>,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(vis = "pub")]
+struct Task {
+    name: Option<String>,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+pub enum Shape {
+    Empty,
+}
+
+fn cargo<T>() -> T::Builder
+where
+    T: Buildable<Builder = CommandBuilder>,
+{
+    let mut builder = T::builder();
+    builder.executable("cargo".to_owned());
+    builder
+}
+
+fn make<T: Buildable>() -> T::Builder {
+    T::builder()
+}
+
+fn main() {
+    let command = cargo::<Command>().build();
+    assert_eq!(command.executable.as_deref(), Some("cargo"));
+
+    let point = make::<Point>().x(1).build();
+    assert_eq!(point, Point { x: Some(1) });
+
+    let job = make::<Job>().id(7).build();
+    assert_eq!(job, Job { id: Some(7) });
+
+    let task = make::<Task>().build();
+    assert_eq!(task, Task { name: None });
+
+    let shape = make::<Shape>().empty().build();
+    assert_eq!(shape, Shape::Empty);
+}
@@ -0,0 +1,24 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(eac = "arg")]
+    args: Vec<String>,
+    #[builder(default, required)]
+    current_dir: Option<String>,
+    executable: String,
+}
+
+#[derive(Builder)]
+pub enum Endpoint {
+    Tcp {
+        #[builder(each = "port")]
+        port: u16,
+    },
+    Unix {
+        #[builder(sub_builder)]
+        path: Option<String>,
+    },
+}
+
+fn main() {}
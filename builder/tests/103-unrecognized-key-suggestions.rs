@@ -0,0 +1,17 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(build_fn(vaildate = "check"))]
+pub struct Command {
+    executable: String,
+}
+
+#[derive(Builder)]
+pub struct Server {
+    #[builder(setter(unknown))]
+    host: String,
+    #[builder(typestate)]
+    port: u16,
+}
+
+fn main() {}
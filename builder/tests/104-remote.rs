@@ -0,0 +1,50 @@
+// A builder can be derived for a struct defined in another crate, or in a
+// module that cannot be changed, by deriving it on a local struct that
+// mirrors its fields and naming the other struct with `remote`.
+
+use derive_builder::Builder;
+
+mod other {
+    #[derive(Debug, PartialEq)]
+    pub struct Config {
+        pub host: String,
+        pub port: u16,
+        pub timeout: Option<u32>,
+        pub tags: Vec<String>,
+    }
+}
+
+#[derive(Builder)]
+#[builder(remote = "other::Config")]
+pub struct ConfigDef {
+    host: String,
+    #[builder(default = "80")]
+    port: u16,
+    timeout: Option<u32>,
+    #[builder(each = "tag")]
+    tags: Vec<String>,
+}
+
+fn main() {
+    let config: other::Config = ConfigDef::builder()
+        .host("localhost".to_owned())
+        .timeout(30)
+        .tag("local".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        config,
+        other::Config {
+            host: "localhost".to_owned(),
+            port: 80,
+            timeout: Some(30),
+            tags: vec!["local".to_owned()],
+        }
+    );
+
+    assert_eq!(
+        ConfigDef::builder().build().unwrap_err().msg(),
+        "field `host` was not set"
+    );
+}
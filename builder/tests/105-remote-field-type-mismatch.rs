@@ -0,0 +1,20 @@
+// The fields of the local struct must have the same types as the fields of
+// the remote struct that it mirrors.
+
+use derive_builder::Builder;
+
+mod other {
+    pub struct Config {
+        pub host: String,
+        pub port: u16,
+    }
+}
+
+#[derive(Builder)]
+#[builder(remote = "other::Config")]
+pub struct ConfigDef {
+    host: String,
+    port: u32,
+}
+
+fn main() {}
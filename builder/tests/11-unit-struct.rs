@@ -4,5 +4,5 @@ use derive_builder::Builder;
 struct UnitStruct;
 
 fn main() {
-    let _ = UnitStruct::builder().build().unwrap();
+    let _ = UnitStruct::builder().build();
 }
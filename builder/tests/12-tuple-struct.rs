@@ -1,6 +1,26 @@
 use derive_builder::Builder;
 
-#[derive(Builder)]
-struct Test(i32, u32, String, Option<String>, Vec<String>);
+#[derive(Builder, Debug)]
+struct Test(i32, u32, String, Option<String>, #[builder(each = "push")] Vec<String>);
 
-fn main() {}
+fn main() {
+    let t = Test::builder()
+        .field_0(-1)
+        .field_1(1)
+        .field_2("two".to_owned())
+        .push("four".to_owned())
+        .push("five".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(t.0, -1);
+    assert_eq!(t.1, 1);
+    assert_eq!(t.2, "two");
+    assert_eq!(t.3, None);
+    assert_eq!(t.4, vec!["four", "five"]);
+
+    assert_eq!(
+        Test::builder().field_0(-1).build().unwrap_err().msg(),
+        "missing fields: `1`, `2`"
+    );
+}
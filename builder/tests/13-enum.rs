@@ -1,9 +1,54 @@
 use derive_builder::Builder;
 
-#[derive(Builder)]
-enum Enum {
-    Option1,
-    Option2,
+#[derive(Builder, Debug, PartialEq)]
+enum Endpoint {
+    Tcp {
+        host: String,
+        port: u16,
+        #[builder(each = "option")]
+        options: Vec<String>,
+    },
+    Unix(String),
+    HTTPProxy {
+        url: Option<String>,
+    },
+    Stdio,
 }
 
-fn main() {}
+fn main() {
+    let tcp = Endpoint::builder()
+        .tcp()
+        .host("localhost".to_owned())
+        .port(8080)
+        .option("nodelay".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        tcp,
+        Endpoint::Tcp {
+            host: "localhost".to_owned(),
+            port: 8080,
+            options: vec!["nodelay".to_owned()],
+        }
+    );
+
+    let unix = Endpoint::builder()
+        .unix()
+        .field_0("/tmp/socket".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(unix, Endpoint::Unix("/tmp/socket".to_owned()));
+
+    let proxy = Endpoint::builder().http_proxy().build();
+    assert_eq!(proxy, Endpoint::HTTPProxy { url: None });
+
+    let stdio = Endpoint::builder().stdio().build();
+    assert_eq!(stdio, Endpoint::Stdio);
+
+    assert_eq!(
+        Endpoint::builder().tcp().port(8080).build().unwrap_err().msg(),
+        "field `host` was not set"
+    );
+}
@@ -26,8 +26,7 @@ fn main() {
         .push_field2(5)
         .push_field3(6)
         .push_field3(7)
-        .build()
-        .unwrap();
+        .build();
 
     assert_eq!(t.field0, vec![0, 1]);
     assert_eq!(t.field1, vec![2, 3]);
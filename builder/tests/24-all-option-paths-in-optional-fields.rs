@@ -10,5 +10,5 @@ struct Test {
 }
 
 fn main() {
-    let _ = Test::builder().build().unwrap();
+    let _ = Test::builder().build();
 }
@@ -20,7 +20,7 @@ struct Test {
 fn main() {
     assert_eq!(
         Test::builder().build().unwrap_err().msg(),
-        "field `option_with_no_generic_arg` was not set"
+        "missing fields: `option_with_no_generic_arg`, `option_with_multiple_generic_args`"
     );
     
     assert_eq!(
@@ -0,0 +1,28 @@
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+struct Test {
+    r#type: u32,
+    r#struct: Option<String>,
+    #[builder(each = "r#fn")]
+    r#impl: Vec<u32>,
+}
+
+fn main() {
+    let t = Test::builder()
+        .r#type(0)
+        .r#struct("struct".to_owned())
+        .r#fn(1)
+        .r#fn(2)
+        .build()
+        .unwrap();
+
+    assert_eq!(t.r#type, 0);
+    assert_eq!(t.r#struct.as_deref(), Some("struct"));
+    assert_eq!(t.r#impl, vec![1, 2]);
+
+    assert_eq!(
+        Test::builder().build().unwrap_err().msg(),
+        "field `type` was not set"
+    );
+}
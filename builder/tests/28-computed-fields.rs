@@ -0,0 +1,39 @@
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(finalize = "compute_checksum")]
+struct Packet {
+    id: u8,
+    #[builder(each = "byte")]
+    payload: Vec<u8>,
+    #[builder(computed)]
+    checksum: u32,
+    #[builder(computed = "String::from(\"unset\")")]
+    summary: String,
+}
+
+fn compute_checksum(packet: &mut Packet) {
+    packet.checksum = packet
+        .payload
+        .iter()
+        .fold(u32::from(packet.id), |acc, &byte| acc + u32::from(byte));
+
+    packet.summary = format!("{} byte(s)", packet.payload.len());
+}
+
+fn main() {
+    let packet = Packet::builder()
+        .id(1)
+        .byte(2)
+        .byte(3)
+        .build()
+        .unwrap();
+
+    assert_eq!(packet.checksum, 6);
+    assert_eq!(packet.summary, "2 byte(s)");
+
+    assert_eq!(
+        Packet::builder().build().unwrap_err().msg(),
+        "field `id` was not set"
+    );
+}
@@ -0,0 +1,10 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+struct Test {
+    field: u32,
+    #[builder(computed)]
+    checksum: u32,
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+// Variant builder methods are named after the variant in snake case. Keywords
+// that cannot be raw identifiers, like `crate`, `self` and `super`, get a
+// trailing underscore instead.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, PartialEq)]
+enum Path {
+    Crate { name: String },
+    Super { levels: usize },
+    SELF(String),
+    Type(String),
+}
+
+fn main() {
+    let path = Path::builder()
+        .crate_()
+        .name("std".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(
+        path,
+        Path::Crate {
+            name: "std".to_owned()
+        }
+    );
+
+    let path = Path::builder().super_().levels(2).build().unwrap();
+    assert_eq!(path, Path::Super { levels: 2 });
+
+    let path = Path::builder()
+        .self_()
+        .field_0("Module".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(path, Path::SELF("Module".to_owned()));
+
+    let path = Path::builder()
+        .r#type()
+        .field_0("Vec".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(path, Path::Type("Vec".to_owned()));
+}
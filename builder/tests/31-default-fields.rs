@@ -0,0 +1,36 @@
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+struct Config {
+    name: String,
+    #[builder(default)]
+    retries: u32,
+    #[builder(default)]
+    tags: Vec<String>,
+    #[builder(default)]
+    comment: Option<String>,
+}
+
+fn main() {
+    let config = Config::builder().name("config".to_owned()).build().unwrap();
+
+    assert_eq!(config.name, "config");
+    assert_eq!(config.retries, 0);
+    assert!(config.tags.is_empty());
+    assert_eq!(config.comment, None);
+
+    let config = Config::builder()
+        .name("config".to_owned())
+        .retries(3)
+        .tags(vec!["tag".to_owned()])
+        .build()
+        .unwrap();
+
+    assert_eq!(config.retries, 3);
+    assert_eq!(config.tags, vec!["tag"]);
+
+    assert_eq!(
+        Config::builder().retries(3).build().unwrap_err().msg(),
+        "field `name` was not set"
+    );
+}
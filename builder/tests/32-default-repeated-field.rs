@@ -0,0 +1,9 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+struct Test {
+    #[builder(each = "arg", default)]
+    args: Vec<String>,
+}
+
+fn main() {}
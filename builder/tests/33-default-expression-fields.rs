@@ -0,0 +1,35 @@
+use derive_builder::Builder;
+
+const DEFAULT_PORT: u16 = 8080;
+
+#[derive(Builder, Debug)]
+struct Config {
+    #[builder(default = "String::from(\"localhost\")")]
+    host: String,
+    #[builder(default = "DEFAULT_PORT + 1")]
+    port: u16,
+    #[builder(default = "Vec::with_capacity(16)")]
+    buffer: Vec<u8>,
+    #[builder(default = "Some(30)")]
+    timeout: Option<u64>,
+}
+
+fn main() {
+    let config = Config::builder().build();
+
+    assert_eq!(config.host, "localhost");
+    assert_eq!(config.port, 8081);
+    assert!(config.buffer.is_empty());
+    assert!(config.buffer.capacity() >= 16);
+    assert_eq!(config.timeout, Some(30));
+
+    let config = Config::builder()
+        .host("example.com".to_owned())
+        .port(80)
+        .timeout(5)
+        .build();
+
+    assert_eq!(config.host, "example.com");
+    assert_eq!(config.port, 80);
+    assert_eq!(config.timeout, Some(5));
+}
@@ -0,0 +1,9 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+struct Test {
+    #[builder(default = "\"eight\"")]
+    field: u32,
+}
+
+fn main() {}
@@ -0,0 +1,38 @@
+use derive_builder::Builder;
+use std::path::PathBuf;
+
+#[derive(Builder, Debug)]
+struct Command {
+    #[builder(setter(into))]
+    executable: String,
+    #[builder(each = "arg", setter(into))]
+    args: Vec<String>,
+    #[builder(setter(into))]
+    current_dir: Option<PathBuf>,
+    #[builder(setter(into), default)]
+    retries: u64,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo")
+        .arg("build")
+        .arg(String::from("--release"))
+        .current_dir("/tmp")
+        .retries(3u8)
+        .build()
+        .unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build", "--release"]);
+    assert_eq!(command.current_dir, Some(PathBuf::from("/tmp")));
+    assert_eq!(command.retries, 3);
+
+    let command = Command::builder()
+        .executable("cargo")
+        .args(["test"].map(String::from).to_vec())
+        .build()
+        .unwrap();
+
+    assert_eq!(command.args, vec!["test"]);
+}
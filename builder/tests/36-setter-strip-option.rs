@@ -0,0 +1,21 @@
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+struct Test {
+    #[builder(setter(strip_option))]
+    field0: Option<u32>,
+    #[builder(setter(strip_option, into))]
+    field1: std::option::Option<String>,
+}
+
+fn main() {
+    let t = Test::builder().field0(0).field1("one").build();
+
+    assert_eq!(t.field0, Some(0));
+    assert_eq!(t.field1.as_deref(), Some("one"));
+
+    let t = Test::builder().build();
+
+    assert_eq!(t.field0, None);
+    assert_eq!(t.field1, None);
+}
@@ -0,0 +1,9 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+struct Test {
+    #[builder(setter(strip_option))]
+    field: Vec<u32>,
+}
+
+fn main() {}
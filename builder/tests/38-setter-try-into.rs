@@ -0,0 +1,31 @@
+use derive_builder::Builder;
+use std::num::TryFromIntError;
+
+#[derive(Builder, Debug)]
+struct Test {
+    #[builder(setter(try_into))]
+    small: u8,
+    #[builder(setter(try_into))]
+    optional: Option<u16>,
+}
+
+fn main() -> Result<(), TryFromIntError> {
+    let t = Test::builder()
+        .try_small(200u32)?
+        .try_optional(300i64)?
+        .build()
+        .unwrap();
+
+    assert_eq!(t.small, 200);
+    assert_eq!(t.optional, Some(300));
+
+    let mut builder = Test::builder();
+    assert!(builder.try_small(256u32).is_err());
+    assert!(builder.try_optional(-1i64).is_err());
+    assert_eq!(builder.build().unwrap_err().msg(), "field `small` was not set");
+
+    let t = Test::builder().small(1).build().unwrap();
+    assert_eq!(t.optional, None);
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(typestate)]
+pub struct Command {
+    #[builder(setter(into))]
+    executable: String,
+    #[builder(setter(try_into))]
+    priority: u8,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+    #[builder(default = "3")]
+    retries: u32,
+}
+
+fn main() {
+    let command = Command::builder()
+        .arg("build".to_owned())
+        .executable("cargo")
+        .current_dir("..".to_owned())
+        .try_priority(7u64)
+        .unwrap()
+        .arg("--release".to_owned())
+        .build();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.priority, 7);
+    assert_eq!(command.args, vec!["build", "--release"]);
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+    assert_eq!(command.retries, 3);
+
+    let builder: CommandBuilder = Command::builder();
+    assert!(builder.executable("cargo").try_priority(256u64).is_err());
+}
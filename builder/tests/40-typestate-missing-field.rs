@@ -0,0 +1,12 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Command {
+    executable: String,
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let _ = Command::builder().current_dir("..".to_owned()).build();
+}
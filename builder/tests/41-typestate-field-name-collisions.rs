@@ -0,0 +1,22 @@
+// Distinct required field names such as `foo` and `foo_` still get distinct
+// type parameters in typestate builders.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(typestate)]
+pub struct Test {
+    foo: u32,
+    foo_: u32,
+    a_1: u32,
+    a1: u32,
+}
+
+fn main() {
+    let test = Test::builder().foo(0).foo_(1).a_1(2).a1(3).build();
+
+    assert_eq!(test.foo, 0);
+    assert_eq!(test.foo_, 1);
+    assert_eq!(test.a_1, 2);
+    assert_eq!(test.a1, 3);
+}
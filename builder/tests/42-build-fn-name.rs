@@ -0,0 +1,37 @@
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(build_fn(name = "finish"))]
+pub struct Command {
+    executable: String,
+    current_dir: Option<String>,
+}
+
+impl CommandBuilder {
+    pub fn build(&mut self) -> String {
+        format!("{:?}", self.finish())
+    }
+}
+
+#[derive(Builder, Debug)]
+#[builder(typestate, build_fn(name = "finish"))]
+pub struct Typestate {
+    field: u32,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .finish()
+        .unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.current_dir, None);
+
+    assert_eq!(
+        Command::builder().build(),
+        r#"Err(CommandBuilderErr { msg: "field `executable` was not set", field: Some(Executable), missing_fields: [Executable], source: None })"#
+    );
+
+    assert_eq!(Typestate::builder().field(0).finish().field, 0);
+}
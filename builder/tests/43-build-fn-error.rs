@@ -0,0 +1,44 @@
+use derive_builder::Builder;
+use std::fmt::{self, Display};
+
+#[derive(Debug, PartialEq)]
+enum ConfigError {
+    Uninitialized(String),
+}
+
+impl From<ConfigBuilderErr> for ConfigError {
+    fn from(err: ConfigBuilderErr) -> Self {
+        ConfigError::Uninitialized(err.msg().to_owned())
+    }
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Uninitialized(msg) => write!(f, "invalid config: {msg}"),
+        }
+    }
+}
+
+#[derive(Builder, Debug)]
+#[builder(build_fn(error = "ConfigError"))]
+struct Config {
+    name: String,
+    port: Option<u16>,
+}
+
+fn main() {
+    let config: Result<Config, ConfigError> = Config::builder().name("config".to_owned()).build();
+    let config = config.unwrap();
+
+    assert_eq!(config.name, "config");
+    assert_eq!(config.port, None);
+
+    let err = Config::builder().port(80).build().unwrap_err();
+
+    assert_eq!(
+        err,
+        ConfigError::Uninitialized("field `name` was not set".to_owned())
+    );
+    assert_eq!(err.to_string(), "invalid config: field `name` was not set");
+}
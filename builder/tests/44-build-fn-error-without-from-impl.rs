@@ -0,0 +1,12 @@
+use derive_builder::Builder;
+
+#[derive(Debug)]
+struct ConfigError;
+
+#[derive(Builder)]
+#[builder(build_fn(error = "ConfigError"))]
+struct Config {
+    name: String,
+}
+
+fn main() {}
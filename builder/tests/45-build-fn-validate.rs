@@ -0,0 +1,63 @@
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(build_fn(validate = "validate_range"))]
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+fn validate_range(range: &Range) -> Result<(), String> {
+    if range.start <= range.end {
+        Ok(())
+    } else {
+        Err(format!("invalid range {}..{}", range.start, range.end))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum PortError {
+    Missing,
+    Reserved(u16),
+}
+
+impl From<PortBuilderErr> for PortError {
+    fn from(_: PortBuilderErr) -> Self {
+        PortError::Missing
+    }
+}
+
+#[derive(Builder, Debug)]
+#[builder(build_fn(error = "PortError", validate = "Port::validate"))]
+struct Port {
+    number: u16,
+}
+
+impl Port {
+    fn validate(&self) -> Result<(), PortError> {
+        if self.number < 1024 {
+            Err(PortError::Reserved(self.number))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn main() {
+    let range = Range::builder().start(1).end(2).build().unwrap();
+    assert_eq!((range.start, range.end), (1, 2));
+
+    assert_eq!(
+        Range::builder().start(2).end(1).build().unwrap_err().msg(),
+        "invalid range 2..1"
+    );
+
+    assert_eq!(
+        Range::builder().start(2).build().unwrap_err().msg(),
+        "field `end` was not set"
+    );
+
+    assert_eq!(Port::builder().number(8080).build().unwrap().number, 8080);
+    assert_eq!(Port::builder().number(80).build().unwrap_err(), PortError::Reserved(80));
+    assert_eq!(Port::builder().build().unwrap_err(), PortError::Missing);
+}
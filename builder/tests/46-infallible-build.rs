@@ -0,0 +1,38 @@
+// When none of the fields are required, build() can never fail, so it
+// returns the struct directly instead of wrapping it in a Result.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, PartialEq)]
+pub struct Style {
+    color: Option<String>,
+    #[builder(default)]
+    bold: bool,
+    #[builder(default = "12")]
+    size: u32,
+    #[builder(each = "font")]
+    fonts: Vec<String>,
+}
+
+#[derive(Builder, Debug)]
+pub struct Label {
+    text: String,
+    style: Option<Style>,
+}
+
+fn main() {
+    let style: Style = Style::builder().bold(true).font("serif".to_owned()).build();
+
+    assert_eq!(
+        style,
+        Style {
+            color: None,
+            bold: true,
+            size: 12,
+            fonts: vec!["serif".to_owned()],
+        }
+    );
+
+    let label: Result<Label, LabelBuilderErr> = Label::builder().style(style).build();
+    assert_eq!(label.unwrap_err().msg(), "field `text` was not set");
+}
@@ -0,0 +1,35 @@
+// The builder struct can be renamed when `<Struct>Builder` is already taken.
+// The error type follows the new name.
+
+use derive_builder::Builder;
+
+pub struct CommandBuilder;
+
+#[derive(Builder, Debug)]
+#[builder(name = "CommandParams")]
+pub struct Command {
+    executable: String,
+    current_dir: Option<String>,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(name = "ShapeParams")]
+pub enum Shape {
+    Circle { radius: u32 },
+}
+
+fn main() {
+    let _ = CommandBuilder;
+
+    let mut params: CommandParams = Command::builder();
+    let command = params.executable("cargo".to_owned()).build().unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.current_dir, None);
+
+    let err: CommandParamsErr = Command::builder().build().unwrap_err();
+    assert_eq!(err.msg(), "field `executable` was not set");
+
+    let params: ShapeParams = Shape::builder();
+    let circle = params.circle().radius(1).build().unwrap();
+    assert_eq!(circle, Shape::Circle { radius: 1 });
+}
@@ -0,0 +1,33 @@
+// The visibility of the generated items can differ from the visibility of
+// the struct on which Builder is derived.
+
+use derive_builder::Builder;
+
+mod net {
+    #[derive(super::Builder, Debug)]
+    #[builder(vis = "pub(crate)")]
+    pub struct Socket {
+        pub port: u16,
+    }
+
+    #[derive(super::Builder, Debug)]
+    #[builder(vis = "pub")]
+    pub struct Endpoint {
+        pub host: String,
+    }
+}
+
+fn main() {
+    let socket = net::Socket::builder().port(80).build().unwrap();
+    assert_eq!(socket.port, 80);
+
+    let endpoint = net::Endpoint::builder()
+        .host("localhost".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(endpoint.host, "localhost");
+
+    let err: net::EndpointBuilderErr = net::Endpoint::builder().build().unwrap_err();
+    assert_eq!(err.msg(), "field `host` was not set");
+}
@@ -0,0 +1,13 @@
+use derive_builder::Builder;
+
+mod net {
+    #[derive(super::Builder, Debug)]
+    #[builder(vis = "")]
+    pub struct Endpoint {
+        pub host: String,
+    }
+}
+
+fn main() {
+    let _ = net::Endpoint::builder();
+}
@@ -0,0 +1,54 @@
+// Extra derives are applied to the generated builder structs, which makes it
+// possible to clone a partially-filled builder.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(derive(Clone, Debug, PartialEq))]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(typestate, derive(Clone))]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(derive(Clone, Debug))]
+pub enum Shape {
+    Circle { radius: u32 },
+}
+
+fn main() {
+    let mut cargo = Command::builder();
+    cargo.executable("cargo".to_owned());
+
+    let mut build = cargo.clone();
+    build.arg("build".to_owned());
+
+    assert_ne!(cargo, build);
+
+    let test = cargo.arg("test".to_owned()).build().unwrap();
+    let build = build.build().unwrap();
+
+    assert_eq!(test.args, vec!["test"]);
+    assert_eq!(build.args, vec!["build"]);
+    assert_eq!(
+        format!("{:?}", Command::builder().arg("-v".to_owned())),
+        r#"CommandBuilder { executable: None, args: ["-v"] }"#
+    );
+
+    let origin = Point::builder().x(0);
+    let a = origin.clone().y(1).build();
+    let b = origin.y(2).build();
+    assert_eq!((a.x, a.y, b.x, b.y), (0, 1, 0, 2));
+
+    let mut circle = Shape::builder().clone().circle();
+    circle.radius(1);
+    assert_eq!(circle.clone().build().unwrap(), Shape::Circle { radius: 1 });
+}
@@ -0,0 +1,37 @@
+// Skipped fields get no setter and are filled automatically at build time,
+// without requiring a finalize hook.
+
+use derive_builder::Builder;
+use std::collections::HashMap;
+
+#[derive(Builder, Debug)]
+pub struct Resolver {
+    server: String,
+    #[builder(skip)]
+    cache: HashMap<String, String>,
+    #[builder(skip = "Some(64)")]
+    cache_capacity: Option<usize>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(typestate)]
+pub struct Counter {
+    name: String,
+    #[builder(skip = "1")]
+    step: u32,
+}
+
+fn main() {
+    let resolver = Resolver::builder()
+        .server("1.1.1.1".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(resolver.server, "1.1.1.1");
+    assert!(resolver.cache.is_empty());
+    assert_eq!(resolver.cache_capacity, Some(64));
+
+    let counter = Counter::builder().name("ticks".to_owned()).build();
+    assert_eq!(counter.name, "ticks");
+    assert_eq!(counter.step, 1);
+}
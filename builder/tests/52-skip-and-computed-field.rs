@@ -0,0 +1,12 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(finalize = "finalize")]
+struct Test {
+    #[builder(computed, skip)]
+    field: u32,
+}
+
+fn finalize(_: &mut Test) {}
+
+fn main() {}
@@ -0,0 +1,47 @@
+// A struct-level prefix is prepended to the names of the setters generated
+// for each field. Setters explicitly named with `each` keep their name.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(setter(prefix = "with_"))]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(setter(try_into))]
+    priority: u8,
+    current_dir: Option<String>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(typestate, setter(prefix = "set_"))]
+pub struct Point(i32, i32);
+
+fn main() {
+    let command = Command::builder()
+        .with_executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .with_current_dir("..".to_owned())
+        .try_with_priority(7_u32)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.priority, 7);
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+
+    let command = Command::builder()
+        .with_executable("cargo".to_owned())
+        .with_args(vec!["test".to_owned()])
+        .with_priority(0)
+        .build()
+        .unwrap();
+
+    assert_eq!(command.args, vec!["test"]);
+
+    let point = Point::builder().set_field_1(2).set_field_0(1).build();
+    assert_eq!((point.0, point.1), (1, 2));
+}
@@ -0,0 +1,9 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(setter(prefix = "with-"))]
+pub struct Command {
+    executable: String,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+// An empty prefix leaves the setters unchanged, including those of fields
+// named with raw identifiers.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(setter(prefix = ""))]
+pub struct Token {
+    r#type: String,
+    value: Option<String>,
+}
+
+fn main() {
+    let token = Token::builder()
+        .r#type("ident".to_owned())
+        .value("x".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(token.r#type, "ident");
+    assert_eq!(token.value.as_deref(), Some("x"));
+}
@@ -0,0 +1,51 @@
+// The `each` attribute also works on maps, in which case the generated
+// method inserts one key-value pair at a time.
+
+use derive_builder::Builder;
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Builder, Debug)]
+pub struct Request {
+    #[builder(each = "header")]
+    headers: HashMap<String, String>,
+    #[builder(each = "param", setter(into))]
+    params: std::collections::BTreeMap<String, String>,
+    #[builder(each = "limits")]
+    limits: BTreeMap<u8, u32>,
+}
+
+fn main() {
+    let request = Request::builder()
+        .header("Accept".to_owned(), "*/*".to_owned())
+        .header("Host".to_owned(), "example.com".to_owned())
+        .header("Accept".to_owned(), "text/html".to_owned())
+        .param("page", "2")
+        .param("sort", "asc")
+        .limits(1, 10)
+        .build();
+
+    assert_eq!(request.headers.len(), 2);
+    assert_eq!(request.headers["Accept"], "text/html");
+    assert_eq!(request.headers["Host"], "example.com");
+
+    let params = request.params.iter().collect::<Vec<_>>();
+    assert_eq!(
+        params,
+        [
+            (&"page".to_owned(), &"2".to_owned()),
+            (&"sort".to_owned(), &"asc".to_owned())
+        ]
+    );
+
+    assert_eq!(request.limits, BTreeMap::from([(1, 10)]));
+
+    let request = Request::builder()
+        .headers(HashMap::from([(
+            "Host".to_owned(),
+            "example.org".to_owned(),
+        )]))
+        .build();
+
+    assert_eq!(request.headers["Host"], "example.org");
+    assert!(request.params.is_empty());
+}
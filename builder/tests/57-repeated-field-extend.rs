@@ -0,0 +1,33 @@
+// Repeated fields get an extend_* method that adds all the elements of an
+// iterator at once, on top of the elements that were already added.
+
+use derive_builder::Builder;
+use std::collections::BTreeMap;
+
+#[derive(Builder, Debug)]
+#[builder(typestate)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(each = "env")]
+    env: BTreeMap<String, String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .extend_args(["--release", "--locked"].map(str::to_owned))
+        .extend_args(Vec::new())
+        .env("RUST_LOG".to_owned(), "info".to_owned())
+        .extend_env(vec![
+            ("RUST_LOG".to_owned(), "debug".to_owned()),
+            ("CARGO_TERM_COLOR".to_owned(), "never".to_owned()),
+        ])
+        .build();
+
+    assert_eq!(command.args, vec!["build", "--release", "--locked"]);
+    assert_eq!(command.env["RUST_LOG"], "debug");
+    assert_eq!(command.env["CARGO_TERM_COLOR"], "never");
+}
@@ -0,0 +1,29 @@
+// The whole-field setter of a repeated field can be omitted, leaving only
+// the setters that append to the field.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    #[builder(each = "arg", setter(append_only))]
+    args: Vec<String>,
+    #[builder(each = "var")]
+    env: Vec<String>,
+}
+
+impl CommandBuilder {
+    pub fn args(&mut self, args: &str) -> &mut Self {
+        self.extend_args(args.split_whitespace().map(str::to_owned))
+    }
+}
+
+fn main() {
+    let command = Command::builder()
+        .arg("build".to_owned())
+        .args("--release --locked")
+        .env(vec!["RUST_LOG=info".to_owned()])
+        .build();
+
+    assert_eq!(command.args, vec!["build", "--release", "--locked"]);
+    assert_eq!(command.env, vec!["RUST_LOG=info"]);
+}
@@ -0,0 +1,9 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(setter(append_only))]
+    args: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,45 @@
+// Setters can take and return the builder by value, which allows storing a
+// configured builder without a separate binding for the builder itself.
+
+use derive_builder::Builder;
+use std::collections::HashMap;
+
+#[derive(Builder, Debug)]
+#[builder(setter(owned))]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(each = "env")]
+    env: HashMap<String, String>,
+    #[builder(setter(try_into))]
+    priority: Option<u8>,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(setter(mutable))]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    let builder = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .env("RUST_LOG".to_owned(), "info".to_owned());
+
+    let builder = builder.extend_args(["--release".to_owned()]);
+    let mut builder = builder.try_priority(3_u32).unwrap();
+
+    let command = builder.build().unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build", "--release"]);
+    assert_eq!(command.env["RUST_LOG"], "info");
+    assert_eq!(command.priority, Some(3));
+
+    let mut builder = Point::builder();
+    builder.x(1);
+    builder.y(2);
+    assert_eq!(builder.build().unwrap(), Point { x: 1, y: 2 });
+}
@@ -0,0 +1,10 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(typestate, setter(mutable))]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+// With build_fn(by_ref), build() clones the stored values instead of moving
+// them out, so the same builder can produce multiple instances.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(build_fn(by_ref))]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+    #[builder(default = "3")]
+    retries: u32,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(build_fn(by_ref))]
+pub struct Style {
+    color: Option<String>,
+}
+
+fn main() {
+    let mut template = Command::builder();
+    template.executable("cargo".to_owned()).arg("build".to_owned());
+
+    let builder = &template;
+    let first = builder.build().unwrap();
+    let second = builder.build().unwrap();
+
+    assert_eq!(first, second);
+    assert_eq!(first.args, vec!["build"]);
+    assert_eq!(first.retries, 3);
+
+    let release = template.arg("--release".to_owned()).build().unwrap();
+    assert_eq!(release.args, vec!["build", "--release"]);
+
+    assert_eq!(
+        Command::builder().build().unwrap_err().msg(),
+        "field `executable` was not set"
+    );
+
+    let mut style = Style::builder();
+    style.color("red".to_owned());
+    assert_eq!(style.build(), style.build());
+}
@@ -0,0 +1,50 @@
+// The builder implements Default, so it can be embedded in structs that
+// derive Default and be constructed generically.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(typestate)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+pub enum Shape {
+    Circle { radius: u32 },
+}
+
+#[derive(Default)]
+struct Task {
+    command: CommandBuilder,
+}
+
+fn new<T: Default>() -> T {
+    T::default()
+}
+
+fn main() {
+    let mut task = Task::default();
+    task.command.executable("cargo".to_owned()).arg("build".to_owned());
+
+    let command = task.command.build().unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+
+    let point = new::<PointBuilder>().x(1).y(2).build();
+    assert_eq!((point.x, point.y), (1, 2));
+
+    let mut circle = new::<ShapeBuilder>().circle();
+    assert_eq!(circle.radius(1).build().unwrap(), Shape::Circle { radius: 1 });
+
+    let mut circle = ShapeCircleBuilder::default();
+    assert_eq!(circle.radius(2).build().unwrap(), Shape::Circle { radius: 2 });
+}
@@ -0,0 +1,12 @@
+// The builder already implements Default, so deriving it as well is an error
+// rather than being silently ignored.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(derive(Clone, Default))]
+pub struct Command {
+    executable: String,
+}
+
+fn main() {}
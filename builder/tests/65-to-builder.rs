@@ -0,0 +1,52 @@
+// A builder can be pre-populated from an existing value, either by
+// converting the value or, if it implements Clone, with to_builder().
+
+use derive_builder::Builder;
+
+#[derive(Builder, Clone, Debug, PartialEq)]
+#[builder(finalize = "count_args")]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+    #[builder(computed)]
+    arg_count: usize,
+}
+
+fn count_args(command: &mut Command) {
+    command.arg_count = command.args.len();
+}
+
+#[derive(Builder, Debug)]
+#[builder(typestate)]
+pub struct Point(i32, i32, Option<i32>);
+
+fn main() {
+    let build = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .build()
+        .unwrap();
+
+    let release = build
+        .to_builder()
+        .arg("--release".to_owned())
+        .current_dir("..".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(build.args, vec!["build"]);
+    assert_eq!(build.arg_count, 1);
+    assert_eq!(release.executable, "cargo");
+    assert_eq!(release.args, vec!["build", "--release"]);
+    assert_eq!(release.current_dir.as_deref(), Some(".."));
+    assert_eq!(release.arg_count, 2);
+
+    let same = CommandBuilder::from(build.clone()).build().unwrap();
+    assert_eq!(same, build);
+
+    let point = Point::builder().field_0(1).field_1(2).build();
+    let moved = PointBuilder::from(point).field_2(3).build();
+    assert_eq!((moved.0, moved.1, moved.2), (1, 2, Some(3)));
+}
@@ -0,0 +1,70 @@
+// With builder(serde), a partial configuration can be deserialized into the
+// builder, completed with the setters and validated by build().
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(serde)]
+pub struct Config {
+    host: String,
+    port: u16,
+    #[builder(each = "tag")]
+    tags: Vec<String>,
+    proxy: Option<String>,
+    #[builder(default = "30")]
+    timeout: u64,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(serde)]
+pub enum Shape {
+    Circle { radius: u32 },
+    Rectangle { width: u32, height: u32 },
+}
+
+fn main() {
+    let mut builder: ConfigBuilder =
+        serde_json::from_str(r#"{ "host": "localhost", "tags": ["a"], "proxy": null }"#).unwrap();
+
+    assert_eq!(
+        builder.build().unwrap_err().msg(),
+        "field `port` was not set"
+    );
+
+    let mut builder: ConfigBuilder =
+        serde_json::from_str(r#"{ "host": "localhost", "tags": ["a"] }"#).unwrap();
+
+    let config = builder.port(8080).tag("b".to_owned()).build().unwrap();
+
+    assert_eq!(config.host, "localhost");
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.tags, vec!["a", "b"]);
+    assert_eq!(config.proxy, None);
+    assert_eq!(config.timeout, 30);
+
+    let config = serde_json::from_str::<ConfigBuilder>(
+        r#"{ "host": "example.com", "port": 80, "timeout": 5 }"#,
+    )
+    .unwrap()
+    .build()
+    .unwrap();
+
+    assert_eq!(config.timeout, 5);
+
+    let mut builder: ShapeRectangleBuilder = serde_json::from_str(r#"{ "width": 2 }"#).unwrap();
+
+    assert_eq!(
+        builder.build().unwrap_err().msg(),
+        "field `height` was not set"
+    );
+
+    let mut builder: ShapeRectangleBuilder = serde_json::from_str(r#"{ "width": 2 }"#).unwrap();
+
+    assert_eq!(
+        builder.height(3).build().unwrap(),
+        Shape::Rectangle {
+            width: 2,
+            height: 3
+        }
+    );
+}
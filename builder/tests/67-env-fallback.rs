@@ -0,0 +1,55 @@
+// Fields can fall back to an environment variable when their setter is not
+// called. The variable is parsed with FromStr.
+
+use derive_builder::Builder;
+use std::env;
+
+#[derive(Builder, Debug)]
+pub struct Config {
+    #[builder(env = "BUILDER_TEST_HOST")]
+    host: String,
+    #[builder(env = "BUILDER_TEST_PORT", default = "80")]
+    port: u16,
+    #[builder(env = "BUILDER_TEST_TIMEOUT")]
+    timeout: Option<u64>,
+}
+
+fn main() {
+    env::remove_var("BUILDER_TEST_HOST");
+    env::remove_var("BUILDER_TEST_PORT");
+    env::remove_var("BUILDER_TEST_TIMEOUT");
+
+    assert_eq!(
+        Config::builder().build().unwrap_err().msg(),
+        "field `host` was not set"
+    );
+
+    let config = Config::builder().host("localhost".to_owned()).build().unwrap();
+    assert_eq!(config.port, 80);
+    assert_eq!(config.timeout, None);
+
+    env::set_var("BUILDER_TEST_HOST", "example.com");
+    env::set_var("BUILDER_TEST_PORT", "8080");
+    env::set_var("BUILDER_TEST_TIMEOUT", "5");
+
+    let config = Config::builder().build().unwrap();
+    assert_eq!(config.host, "example.com");
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.timeout, Some(5));
+
+    let config = Config::builder()
+        .host("localhost".to_owned())
+        .port(443)
+        .build()
+        .unwrap();
+
+    assert_eq!(config.host, "localhost");
+    assert_eq!(config.port, 443);
+
+    env::set_var("BUILDER_TEST_PORT", "http");
+
+    assert_eq!(
+        Config::builder().build().unwrap_err().msg(),
+        "environment variable `BUILDER_TEST_PORT` for field `port` could not be parsed"
+    );
+}
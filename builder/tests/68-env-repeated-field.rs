@@ -0,0 +1,9 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(each = "arg", env = "ARGS")]
+    args: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,57 @@
+// Doc comments on the fields are copied onto the generated setters and
+// builder fields. This should not get in the way of any kind of field.
+
+#![deny(missing_docs)]
+
+//! Crate documentation.
+
+use derive_builder::Builder;
+
+/// A command to run.
+#[derive(Builder, Debug)]
+#[builder(finalize = "finalize")]
+pub struct Command {
+    /// The program to run.
+    ///
+    /// Looked up in `PATH` if it is not an absolute path.
+    executable: String,
+    /// The arguments passed to the program.
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    /// The directory to run the program in.
+    #[doc = "Defaults to the current directory."]
+    current_dir: Option<String>,
+    /// The number of arguments, filled in by `finalize`.
+    #[builder(computed)]
+    arg_count: usize,
+}
+
+fn finalize(command: &mut Command) {
+    command.arg_count = command.args.len();
+}
+
+/// A point.
+#[derive(Builder, Debug)]
+#[builder(typestate)]
+pub struct Point {
+    /// The horizontal coordinate.
+    x: i32,
+    /// The vertical coordinate.
+    #[builder(setter(try_into))]
+    y: i32,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.current_dir, None);
+    assert_eq!(command.arg_count, 1);
+
+    let point = Point::builder().x(1).try_y(2_i64).unwrap().build();
+    assert_eq!((point.x, point.y), (1, 2));
+}
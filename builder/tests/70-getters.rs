@@ -0,0 +1,51 @@
+// The builder has getters that can be used to inspect which values were
+// supplied before calling build().
+
+use derive_builder::Builder;
+use std::collections::BTreeMap;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(each = "env")]
+    env: BTreeMap<String, String>,
+    current_dir: Option<String>,
+    #[builder(default)]
+    retries: u32,
+}
+
+#[derive(Builder, Debug)]
+#[builder(typestate)]
+pub struct Point {
+    x: i32,
+    y: i32,
+    label: Option<String>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    assert_eq!(builder.get_executable(), None);
+    assert!(builder.get_args().is_empty());
+    assert!(builder.get_env().is_empty());
+
+    builder
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .env("RUST_LOG".to_owned(), "info".to_owned())
+        .retries(3);
+
+    assert_eq!(builder.get_executable().map(String::as_str), Some("cargo"));
+    assert_eq!(builder.get_args(), ["build"]);
+    assert_eq!(builder.get_env()["RUST_LOG"], "info");
+    assert_eq!(builder.get_current_dir(), None);
+    assert_eq!(builder.get_retries(), Some(&3));
+
+    let builder = Point::builder().x(1).label("origin".to_owned());
+    assert_eq!(*builder.get_x(), 1);
+    assert_eq!(builder.get_label().map(String::as_str), Some("origin"));
+
+    let builder = builder.y(2);
+    assert_eq!((*builder.get_x(), *builder.get_y()), (1, 2));
+}
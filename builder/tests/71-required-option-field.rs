@@ -0,0 +1,54 @@
+// An Option field marked with builder(required) must be set explicitly,
+// possibly to None, before calling build().
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    executable: String,
+    #[builder(required)]
+    current_dir: Option<String>,
+    #[builder(required, setter(into))]
+    timeout: Option<u64>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(typestate)]
+pub struct Parent {
+    #[builder(required)]
+    id: Option<u32>,
+}
+
+fn main() {
+    assert_eq!(
+        Command::builder()
+            .executable("cargo".to_owned())
+            .timeout(5)
+            .build()
+            .unwrap_err()
+            .msg(),
+        "field `current_dir` was not set"
+    );
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .current_dir(None)
+        .timeout(5)
+        .build()
+        .unwrap();
+
+    assert_eq!(command.current_dir, None);
+    assert_eq!(command.timeout, Some(5));
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .current_dir(Some("..".to_owned()))
+        .timeout(None)
+        .build()
+        .unwrap();
+
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+    assert_eq!(command.timeout, None);
+
+    assert_eq!(Parent::builder().id(None).build().id, None);
+}
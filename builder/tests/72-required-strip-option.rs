@@ -0,0 +1,9 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(required, setter(strip_option))]
+    current_dir: Option<String>,
+}
+
+fn main() {}
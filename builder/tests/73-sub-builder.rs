@@ -0,0 +1,62 @@
+// Fields whose type also derives Builder can be built through a nested
+// builder, which is built automatically when the outer struct is built.
+
+use derive_builder::Builder;
+use std::error::Error;
+
+mod net {
+    #[derive(derive_builder::Builder, Clone, Debug, PartialEq)]
+    pub struct Listener {
+        pub host: String,
+        pub port: u16,
+    }
+}
+
+#[derive(Builder, Clone, Debug, PartialEq)]
+pub struct Limits {
+    connections: Option<u32>,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+pub struct Server {
+    name: String,
+    #[builder(sub_builder)]
+    listener: net::Listener,
+    #[builder(sub_builder)]
+    limits: Limits,
+}
+
+fn main() {
+    let mut builder = Server::builder();
+    builder.name("web".to_owned());
+    builder.listener_builder().host("localhost".to_owned());
+
+    let err = builder.build().unwrap_err();
+    assert_eq!(
+        err.msg(),
+        "field `listener` could not be built: field `port` was not set"
+    );
+
+    let source = err
+        .source()
+        .and_then(|source| source.downcast_ref::<net::ListenerBuilderErr>())
+        .unwrap();
+    assert_eq!(source.msg(), "field `port` was not set");
+
+    let mut builder = Server::builder();
+    builder.name("web".to_owned());
+    builder.listener_builder().host("localhost".to_owned()).port(80);
+    builder.limits_builder().connections(10);
+
+    let server = builder.build().unwrap();
+    assert_eq!(server.listener.host, "localhost");
+    assert_eq!(server.listener.port, 80);
+    assert_eq!(server.limits.connections, Some(10));
+
+    let mut builder = ServerBuilder::from(server);
+    builder.listener_builder().port(8080);
+
+    let server = builder.build().unwrap();
+    assert_eq!(server.listener.port, 8080);
+    assert_eq!(server.limits.connections, Some(10));
+}
@@ -0,0 +1,15 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(typestate)]
+pub struct Server {
+    #[builder(sub_builder)]
+    listener: Listener,
+}
+
+#[derive(Builder)]
+pub struct Listener {
+    port: u16,
+}
+
+fn main() {}
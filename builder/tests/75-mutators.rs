@@ -0,0 +1,54 @@
+// Hand-written methods can be added to the generated builder.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(mutators {
+    /// Sets the host and port at once.
+    pub fn address(&mut self, address: &str) -> &mut Self {
+        let (host, port) = address.split_once(':').unwrap();
+        self.host = Some(host.to_owned());
+        self.port = Some(port.parse().unwrap());
+        self
+    }
+
+    pub fn header(&mut self, name: &str, value: &str) -> &mut Self {
+        self.headers.push(format!("{name}: {value}"));
+        self
+    }
+})]
+pub struct Request {
+    host: String,
+    port: u16,
+    #[builder(each = "raw_header")]
+    headers: Vec<String>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(typestate, mutators(
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_owned());
+        self
+    }
+))]
+pub struct Point {
+    x: i32,
+    #[builder(each = "raw_tag")]
+    tags: Vec<String>,
+}
+
+fn main() {
+    let request = Request::builder()
+        .address("localhost:8080")
+        .header("Accept", "*/*")
+        .raw_header("Host: localhost".to_owned())
+        .build()
+        .unwrap();
+
+    assert_eq!(request.host, "localhost");
+    assert_eq!(request.port, 8080);
+    assert_eq!(request.headers, vec!["Accept: */*", "Host: localhost"]);
+
+    let point = Point::builder().tag("origin").x(0).build();
+    assert_eq!(point.tags, vec!["origin"]);
+}
@@ -0,0 +1,11 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(mutators {
+    pub fn reset(&mut self) {}
+})]
+pub enum Shape {
+    Circle { radius: u32 },
+}
+
+fn main() {}
@@ -0,0 +1,55 @@
+// A field can be accumulated in the builder as a different, more convenient
+// type, which is converted into the type of the field by an expression when
+// the struct is built.
+
+use derive_builder::Builder;
+use std::sync::Arc;
+
+#[derive(Builder, Clone, Debug, PartialEq)]
+#[builder(mutators {
+    pub fn arg(&mut self, arg: &str) -> &mut Self {
+        self.args.push(arg.to_owned());
+        self
+    }
+})]
+pub struct Command {
+    executable: String,
+    #[builder(field(ty = "Vec<String>", build = "self.args.into_boxed_slice()"))]
+    args: Box<[String]>,
+}
+
+#[derive(Builder, Debug)]
+pub struct Label {
+    #[builder(field(ty = "String", build = "Arc::from(self.text)"), setter(into))]
+    text: Arc<str>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(build_fn(by_ref))]
+pub struct Histogram {
+    #[builder(field(ty = "Vec<u32>", build = "self.total.iter().sum()"))]
+    total: u32,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    builder.executable("cargo".to_owned()).arg("build");
+    builder.arg("--release");
+    assert_eq!(builder.get_args(), &["build", "--release"]);
+
+    let command = builder.build().unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(*command.args, ["build", "--release"]);
+
+    let copy = command.to_builder().arg("--locked").build().unwrap();
+    assert_eq!(*copy.args, ["build", "--release", "--locked"]);
+
+    let label = Label::builder().text("hello").build();
+    assert_eq!(&*label.text, "hello");
+    assert_eq!(&*Label::builder().build().text, "");
+
+    let mut builder = Histogram::builder();
+    builder.total(vec![1, 2, 3]);
+    assert_eq!(builder.build().total, 6);
+    assert_eq!(builder.build().total, 6);
+}
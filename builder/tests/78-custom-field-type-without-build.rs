@@ -0,0 +1,9 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(field(ty = "Vec<String>"))]
+    args: Box<[String]>,
+}
+
+fn main() {}
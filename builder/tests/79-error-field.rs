@@ -0,0 +1,60 @@
+// The builder error identifies the field that caused it, so that callers can
+// match on it instead of parsing the error message.
+
+use derive_builder::Builder;
+use std::error::Error;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    executable: String,
+    #[builder(env = "DERIVE_BUILDER_TEST_75_JOBS")]
+    jobs: u32,
+    current_dir: Option<String>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(build_fn(validate = "check_point"))]
+pub struct Point(i32, i32);
+
+fn check_point(point: &Point) -> Result<(), String> {
+    if point.0 == point.1 {
+        Err("point is on the diagonal".to_owned())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Builder, Debug)]
+pub enum Endpoint {
+    Tcp { host: String, port: u16 },
+    Stdio,
+}
+
+fn main() {
+    let err = Command::builder().jobs(4).build().unwrap_err();
+    assert_eq!(err.field(), Some(CommandBuilderField::Executable));
+    assert_eq!(err.to_string(), "field `executable` was not set");
+    assert!(err.source().is_none());
+
+    std::env::set_var("DERIVE_BUILDER_TEST_75_JOBS", "many");
+    let err = Command::builder()
+        .executable("cargo".to_owned())
+        .build()
+        .unwrap_err();
+    assert_eq!(err.field(), Some(CommandBuilderField::Jobs));
+
+    let err = Point::builder().field_0(1).build().unwrap_err();
+    assert_eq!(err.field(), Some(PointBuilderField::Field1));
+
+    let err = Point::builder().field_0(1).field_1(1).build().unwrap_err();
+    assert_eq!(err.field(), None);
+    assert_eq!(err.msg(), "point is on the diagonal");
+
+    let err = Endpoint::builder().tcp().host("localhost".to_owned()).build().unwrap_err();
+    match err.field() {
+        Some(EndpointBuilderField::TcpPort) => {}
+        field => panic!("unexpected field: {field:?}"),
+    }
+
+    let _ = CommandBuilderField::CurrentDir;
+}
@@ -0,0 +1,49 @@
+// Distinct field names such as `a_1` and `a1` map to the same error field
+// variant once converted to upper camel case, so the later ones are
+// disambiguated with their index. Names that are left without a leading
+// letter, such as `_0` or `__`, are prefixed with `Field`.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Test {
+    a_1: u32,
+    a1: u32,
+    foo: u32,
+    foo_: u32,
+    _0: u32,
+    _1: u32,
+    __: u32,
+}
+
+fn unset_field(builder: &mut TestBuilder) -> Option<TestBuilderField> {
+    builder.build().unwrap_err().field()
+}
+
+fn main() {
+    let mut builder = Test::builder();
+    builder.a1(0).foo(0).foo_(0)._0(0)._1(0).__(0);
+    assert_eq!(unset_field(&mut builder), Some(TestBuilderField::A1));
+
+    let mut builder = Test::builder();
+    builder.a_1(0).foo(0).foo_(0)._0(0)._1(0).__(0);
+    assert_eq!(unset_field(&mut builder), Some(TestBuilderField::A11));
+
+    let mut builder = Test::builder();
+    builder.a_1(0).a1(0).foo(0)._0(0)._1(0).__(0);
+    assert_eq!(unset_field(&mut builder), Some(TestBuilderField::Foo3));
+
+    let mut builder = Test::builder();
+    builder.a_1(0).a1(0).foo(0).foo_(0)._1(0).__(0);
+    assert_eq!(unset_field(&mut builder), Some(TestBuilderField::Field0));
+
+    let mut builder = Test::builder();
+    builder.a_1(0).a1(0).foo(0).foo_(0)._0(0).__(0);
+    assert_eq!(unset_field(&mut builder), Some(TestBuilderField::Field1));
+
+    let mut builder = Test::builder();
+    builder.a_1(0).a1(0).foo(0).foo_(0)._0(0)._1(0);
+    let err = builder.build().unwrap_err();
+    assert_eq!(err.field(), Some(TestBuilderField::Field));
+    assert_eq!(err.msg(), "field `__` was not set");
+}
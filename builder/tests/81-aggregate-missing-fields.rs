@@ -0,0 +1,46 @@
+// When several required fields are not set, the error reports all of them
+// instead of only the first one.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Config {
+    host: String,
+    port: u16,
+    user: Option<String>,
+    #[builder(env = "DERIVE_BUILDER_TEST_76_PASSWORD")]
+    password: String,
+    #[builder(default = "30")]
+    timeout: u32,
+}
+
+fn main() {
+    let err = Config::builder().build().unwrap_err();
+    assert_eq!(err.msg(), "missing fields: `host`, `port`, `password`");
+    assert_eq!(err.field(), Some(ConfigBuilderField::Host));
+    assert_eq!(
+        err.missing_fields(),
+        [
+            ConfigBuilderField::Host,
+            ConfigBuilderField::Port,
+            ConfigBuilderField::Password,
+        ],
+    );
+
+    std::env::set_var("DERIVE_BUILDER_TEST_76_PASSWORD", "hunter2");
+
+    let mut builder = Config::builder();
+    builder.port(22);
+
+    let err = builder.build().unwrap_err();
+    assert_eq!(err.msg(), "field `host` was not set");
+    assert_eq!(err.missing_fields(), [ConfigBuilderField::Host]);
+
+    // The builder is left untouched when fields are missing.
+    let config = builder.host("localhost".to_owned()).build().unwrap();
+    assert_eq!(config.host, "localhost");
+    assert_eq!(config.port, 22);
+    assert_eq!(config.user, None);
+    assert_eq!(config.password, "hunter2");
+    assert_eq!(config.timeout, 30);
+}
@@ -0,0 +1,59 @@
+// With the `no_std` attribute, the generated code only refers to `core` and
+// `alloc`, so it can be used in crates that do not link the standard library.
+
+#![no_std]
+
+extern crate alloc;
+// Linked only to provide the runtime of this test binary; the generated code
+// cannot refer to it as `std`.
+extern crate std as runtime;
+
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(no_std)]
+pub struct Device {
+    name: String,
+    #[builder(setter(into))]
+    address: u16,
+    label: core::option::Option<String>,
+    #[builder(each = "register")]
+    registers: alloc::vec::Vec<u32>,
+    #[builder(each = "pin")]
+    pins: BTreeMap<String, u8>,
+    #[builder(default)]
+    flags: Vec<bool>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(no_std, typestate)]
+pub struct Led {
+    pin: u8,
+}
+
+fn main() {
+    let device = Device::builder()
+        .name("sensor".to_owned())
+        .address(0x40_u8)
+        .register(1)
+        .register(2)
+        .pin("sda".to_owned(), 4)
+        .build()
+        .unwrap();
+
+    assert_eq!(device.name, "sensor");
+    assert_eq!(device.address, 0x40);
+    assert_eq!(device.label, None);
+    assert_eq!(device.registers, [1, 2]);
+    assert_eq!(device.pins["sda"], 4);
+    assert!(device.flags.is_empty());
+
+    let err = Device::builder().build().unwrap_err();
+    assert_eq!(err.to_string(), "missing fields: `name`, `address`");
+
+    assert_eq!(Led::builder().pin(13).build().pin, 13);
+}
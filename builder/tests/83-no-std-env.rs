@@ -0,0 +1,15 @@
+#![no_std]
+
+extern crate alloc;
+extern crate std as runtime;
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(no_std)]
+pub struct Device {
+    #[builder(env = "DEVICE_ADDRESS")]
+    address: u16,
+}
+
+fn main() {}
@@ -0,0 +1,66 @@
+// Fields gated by `#[cfg(...)]` are removed by the compiler before the derive
+// macro runs, so nothing is generated for disabled fields and the builder
+// compiles whether or not they are enabled.
+//
+// `cfg(any())` is always disabled and `cfg(all())` is always enabled.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Clone, Debug)]
+pub struct Command {
+    executable: String,
+    #[cfg(any())]
+    jobs: u32,
+    #[cfg(all())]
+    verbose: bool,
+    #[cfg(any())]
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[cfg(any())]
+    current_dir: Option<String>,
+    #[cfg(any())]
+    #[builder(field(ty = "Vec<u8>", build = "self.stdin.into_boxed_slice()"))]
+    stdin: Box<[u8]>,
+    #[cfg(any())]
+    #[builder(skip)]
+    pid: u32,
+}
+
+#[derive(Builder, Debug)]
+#[builder(typestate)]
+pub struct Point {
+    x: i32,
+    #[cfg(any())]
+    z: Option<i32>,
+}
+
+#[derive(Builder, Debug)]
+pub enum Shape {
+    Circle {
+        radius: u32,
+        #[cfg(any())]
+        center: (i32, i32),
+    },
+}
+
+fn main() {
+    let err = Command::builder().build().unwrap_err();
+    assert_eq!(err.msg(), "missing fields: `executable`, `verbose`");
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .verbose(true)
+        .build()
+        .unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert!(command.verbose);
+
+    let command = command.to_builder().verbose(false).build().unwrap();
+    assert!(!command.verbose);
+
+    assert_eq!(Point::builder().x(1).build().x, 1);
+
+    let shape = Shape::builder().circle().radius(2).build().unwrap();
+    assert!(matches!(shape, Shape::Circle { radius: 2 }));
+}
@@ -0,0 +1,42 @@
+// The setter of a field can be renamed, which is mostly useful to avoid
+// having to call setters named with raw identifiers.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(setter(prefix = "with_"))]
+pub struct Token {
+    #[builder(setter(name = "type_", try_into))]
+    r#type: u8,
+    r#struct: Option<String>,
+    #[builder(each = "r#fn", setter(name = "fns"))]
+    r#impl: Vec<u32>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(typestate)]
+pub struct Keyword {
+    #[builder(setter(name = "match_"))]
+    r#match: bool,
+}
+
+fn main() {
+    let token = Token::builder()
+        .type_(1)
+        .with_struct("struct".to_owned())
+        .fns(vec![1])
+        .r#fn(2)
+        .build()
+        .unwrap();
+
+    assert_eq!(token.r#type, 1);
+    assert_eq!(token.r#struct.as_deref(), Some("struct"));
+    assert_eq!(token.r#impl, vec![1, 2]);
+
+    let mut builder = Token::builder();
+    assert!(builder.try_type_(256).is_err());
+    assert!(builder.try_type_(255_u32).is_ok());
+    assert_eq!(builder.get_type(), Some(&255));
+
+    assert!(Keyword::builder().match_(true).build().r#match);
+}
@@ -0,0 +1,55 @@
+// Optional fields also get a setter that accepts the Option itself, so that
+// already optional values can be forwarded as is.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Command {
+    executable: String,
+    current_dir: Option<String>,
+    #[builder(default = "Some(1)")]
+    jobs: Option<u32>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(typestate, setter(prefix = "with_"))]
+pub struct Point {
+    x: i32,
+    z: Option<i32>,
+}
+
+fn main() {
+    let current_dir = std::env::args().nth(100);
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .maybe_current_dir(current_dir)
+        .build()
+        .unwrap();
+
+    assert_eq!(command.current_dir, None);
+    assert_eq!(command.jobs, Some(1));
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .current_dir("..".to_owned())
+        .maybe_current_dir(Some("/".to_owned()))
+        .maybe_jobs(Some(4))
+        .build()
+        .unwrap();
+
+    assert_eq!(command.current_dir.as_deref(), Some("/"));
+    assert_eq!(command.jobs, Some(4));
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .jobs(4)
+        .maybe_jobs(None)
+        .build()
+        .unwrap();
+
+    assert_eq!(command.jobs, Some(1));
+
+    let point = Point::builder().maybe_with_z(Some(3)).with_x(1).build();
+    assert_eq!(point.z, Some(3));
+}
@@ -0,0 +1,63 @@
+// With the `const` attribute, `builder()`, the setters and `build()` are
+// `const fn`, so that constants can be assembled with the builder.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(const)]
+pub struct Config {
+    host: &'static str,
+    port: u16,
+    retries: Option<u8>,
+    #[builder(default = "Some(30)")]
+    timeout: Option<u32>,
+    #[builder(default = "8")]
+    workers: u32,
+    #[builder(skip = "0")]
+    connections: u32,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(const, typestate, setter(prefix = "with_"))]
+pub struct Point {
+    x: i32,
+    y: i32,
+    z: Option<i32>,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(const)]
+pub enum Shape {
+    Circle { radius: u32 },
+}
+
+const CONFIG: Config = Config::builder()
+    .host("localhost")
+    .port(8080)
+    .maybe_retries(Some(3))
+    .build();
+
+const ORIGIN: Point = Point::builder().with_y(0).with_x(0).build();
+
+const CIRCLE: Shape = Shape::builder().circle().radius(1).build();
+
+fn main() {
+    assert_eq!(
+        CONFIG,
+        Config {
+            host: "localhost",
+            port: 8080,
+            retries: Some(3),
+            timeout: Some(30),
+            workers: 8,
+            connections: 0,
+        }
+    );
+
+    assert_eq!(ORIGIN, Point { x: 0, y: 0, z: None });
+    assert_eq!(CIRCLE, Shape::Circle { radius: 1 });
+
+    // The builder can still be used at runtime.
+    let config = Config::builder().host("example.com").port(443).build();
+    assert_eq!(config.port, 443);
+}
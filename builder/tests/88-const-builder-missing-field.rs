@@ -0,0 +1,14 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(const)]
+pub struct Config {
+    host: &'static str,
+    port: u16,
+}
+
+const CONFIG: Config = Config::builder().host("localhost").build();
+
+fn main() {
+    let _ = CONFIG.port;
+}
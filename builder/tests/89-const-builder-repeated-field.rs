@@ -0,0 +1,10 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(const)]
+pub struct Config {
+    #[builder(each = "host")]
+    hosts: Vec<&'static str>,
+}
+
+fn main() {}
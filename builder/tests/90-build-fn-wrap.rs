@@ -0,0 +1,73 @@
+// With `build_fn(wrap = "...")`, the built value is wrapped with the `new`
+// function of the given type, which is handy for shared configuration that
+// always ends up behind an `Arc`.
+
+use derive_builder::Builder;
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(build_fn(wrap = "Arc"))]
+pub struct Config {
+    name: String,
+    jobs: Option<u32>,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(build_fn(wrap = "Box", by_ref))]
+pub struct Options {
+    verbose: Option<bool>,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(typestate, build_fn(wrap = "std::rc::Rc"))]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(build_fn(wrap = "Arc"))]
+pub enum Shape {
+    Circle { radius: u32 },
+}
+
+#[derive(Builder, Debug, PartialEq)]
+pub struct App {
+    #[builder(sub_builder)]
+    config: Config,
+}
+
+fn main() {
+    let config: Arc<Config> = Config::builder()
+        .name("web".to_owned())
+        .jobs(4)
+        .build()
+        .unwrap();
+
+    assert_eq!(config.name, "web");
+    assert_eq!(config.jobs, Some(4));
+
+    assert_eq!(
+        Config::builder().build().unwrap_err().msg(),
+        "field `name` was not set"
+    );
+
+    let mut builder = Options::builder();
+    builder.verbose(true);
+    let options: Box<Options> = builder.build();
+    assert_eq!(*options, Options { verbose: Some(true) });
+    assert_eq!(builder.build(), options);
+
+    let point: Rc<Point> = Point::builder().x(1).y(2).build();
+    assert_eq!(*point, Point { x: 1, y: 2 });
+
+    let shape: Arc<Shape> = Shape::builder().circle().radius(1).build().unwrap();
+    assert_eq!(*shape, Shape::Circle { radius: 1 });
+
+    // Nested builders still produce the unwrapped value.
+    let mut builder = App::builder();
+    builder.config_builder().name("web".to_owned());
+    let app: App = builder.build().unwrap();
+    assert_eq!(app.config.name, "web");
+}
@@ -0,0 +1,90 @@
+// Every field of the builder can be reverted to the state it had before any
+// of its setters was called, so that a builder can be reused as a template
+// with some of its fields swapped between builds.
+
+use derive_builder::Builder;
+use std::collections::BTreeMap;
+
+#[derive(Builder, Clone, Debug, PartialEq)]
+#[builder(derive(Clone))]
+pub struct Limits {
+    connections: Option<u32>,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(build_fn(by_ref))]
+pub struct Command {
+    executable: String,
+    current_dir: Option<String>,
+    #[builder(default = "1")]
+    jobs: u32,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(each = "env")]
+    envs: BTreeMap<String, String>,
+    #[builder(sub_builder)]
+    limits: Limits,
+    #[builder(field(ty = "Vec<u32>", build = "self.samples.iter().sum()"))]
+    samples: u32,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(typestate)]
+pub struct Point {
+    x: i32,
+    z: Option<i32>,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(const, setter(owned))]
+pub struct Size(u32, Option<u32>);
+
+const SIZE: Size = Size::builder().field_0(1).field_1(2).clear_field_1().build();
+
+fn main() {
+    let mut builder = Command::builder();
+    builder
+        .executable("cargo".to_owned())
+        .current_dir("..".to_owned())
+        .jobs(4)
+        .arg("build".to_owned())
+        .env("RUST_LOG".to_owned(), "debug".to_owned())
+        .samples(vec![1, 2]);
+    builder.limits_builder().connections(8);
+
+    let command = builder.build().unwrap();
+    assert_eq!(command.jobs, 4);
+    assert_eq!(command.limits.connections, Some(8));
+    assert_eq!(command.samples, 3);
+
+    builder
+        .clear_current_dir()
+        .clear_jobs()
+        .clear_args()
+        .clear_envs()
+        .clear_limits()
+        .clear_samples();
+
+    assert_eq!(
+        builder.build().unwrap(),
+        Command {
+            executable: "cargo".to_owned(),
+            current_dir: None,
+            jobs: 1,
+            args: Vec::new(),
+            envs: BTreeMap::new(),
+            limits: Limits { connections: None },
+            samples: 0,
+        }
+    );
+
+    assert_eq!(
+        builder.clear_executable().build().unwrap_err().msg(),
+        "field `executable` was not set"
+    );
+
+    let point = Point::builder().z(3).x(1).clear_z().build();
+    assert_eq!(point, Point { x: 1, z: None });
+
+    assert_eq!(SIZE, Size(1, None));
+}
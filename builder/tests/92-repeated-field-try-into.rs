@@ -0,0 +1,38 @@
+// Fields marked with `each` can also be given a fallible setter, which
+// converts each element before adding it to the collection.
+
+use derive_builder::Builder;
+use std::collections::HashMap;
+use std::num::TryFromIntError;
+
+#[derive(Builder, Debug)]
+struct Test {
+    #[builder(each = "port", setter(try_into))]
+    ports: Vec<u16>,
+    #[builder(each = "limit", setter(into, try_into))]
+    limits: HashMap<String, u8>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(setter(owned))]
+struct Owned {
+    #[builder(each = "byte", setter(try_into, append_only))]
+    bytes: Vec<u8>,
+}
+
+fn main() -> Result<(), TryFromIntError> {
+    let mut builder = Test::builder();
+    builder.try_port(80u32)?.port(443).try_limit("jobs", 16i64)?;
+
+    assert!(builder.try_port(70_000u32).is_err());
+    assert!(builder.try_limit("files", 1_000i64).is_err());
+
+    let t = builder.build();
+    assert_eq!(t.ports, [80, 443]);
+    assert_eq!(t.limits, HashMap::from([("jobs".to_owned(), 16)]));
+
+    let owned = Owned::builder().try_byte(1i32)?.byte(2).build();
+    assert_eq!(owned.bytes, [1, 2]);
+
+    Ok(())
+}
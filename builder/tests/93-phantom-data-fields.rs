@@ -0,0 +1,38 @@
+// PhantomData fields carry no value, so they are skipped automatically
+// instead of requiring a setter call.
+
+use derive_builder::Builder;
+use std::marker::PhantomData;
+
+pub struct Meters;
+
+#[derive(Builder, Debug, PartialEq)]
+pub struct Distance {
+    value: f64,
+    unit: PhantomData<Meters>,
+    not_send: std::marker::PhantomData<*const ()>,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(typestate)]
+pub struct Marker(u32, PhantomData<fn() -> Meters>);
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(const)]
+pub struct Id {
+    value: u64,
+    kind: PhantomData<Meters>,
+}
+
+const ID: Id = Id::builder().value(7).build();
+
+fn main() {
+    let distance = Distance::builder().value(1.5).build().unwrap();
+    assert_eq!(distance.value, 1.5);
+    assert_eq!(distance.unit, PhantomData);
+
+    let marker = Marker::builder().field_0(1).build();
+    assert_eq!(marker, Marker(1, PhantomData));
+
+    assert_eq!(ID.value, 7);
+}
@@ -0,0 +1,76 @@
+// The setters can follow a different naming convention than the fields,
+// e.g. to mirror the naming of an external API.
+
+#![deny(warnings)]
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+#[builder(rename_all = "camelCase")]
+pub struct Request {
+    request_id: u64,
+    max_retry_count: Option<u32>,
+    #[builder(each = "header")]
+    extra_headers: Vec<String>,
+    #[builder(setter(name = "kind"))]
+    request_kind: String,
+    #[builder(setter(try_into))]
+    timeout_ms: u64,
+    r#type: String,
+    crate_: Option<String>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(rename_all = "PascalCase", setter(prefix = "set_"))]
+pub struct Window {
+    title: String,
+    width: Option<u32>,
+}
+
+#[derive(Builder, Debug)]
+#[builder(rename_all = "SCREAMING_SNAKE_CASE", typestate)]
+pub struct Point {
+    pos_x: i32,
+    pos_y: Option<i32>,
+}
+
+fn main() {
+    let mut builder = Request::builder();
+    builder
+        .requestId(1)
+        .maybeMaxRetryCount(Some(3))
+        .header("Accept".to_owned())
+        .extendExtraHeaders(vec!["Host".to_owned()])
+        .kind("GET".to_owned())
+        .tryTimeoutMs(500_u16)
+        .unwrap()
+        .r#type("json".to_owned())
+        .crate_("std".to_owned());
+
+    assert_eq!(builder.getRequestId(), Some(&1));
+    assert_eq!(builder.getMaxRetryCount(), Some(&3));
+
+    let request = builder.clearMaxRetryCount().build().unwrap();
+
+    assert_eq!(request.request_id, 1);
+    assert_eq!(request.max_retry_count, None);
+    assert_eq!(request.extra_headers, ["Accept", "Host"]);
+    assert_eq!(request.request_kind, "GET");
+    assert_eq!(request.timeout_ms, 500);
+    assert_eq!(request.r#type, "json");
+    assert_eq!(request.crate_.as_deref(), Some("std"));
+
+    let window = Window::builder()
+        .SetTitle("main".to_owned())
+        .MaybeSetWidth(Some(640))
+        .build()
+        .unwrap();
+    assert_eq!(window.title, "main");
+    assert_eq!(window.width, Some(640));
+
+    let point = Point::builder().POS_Y(2).POS_X(1);
+    assert_eq!(*point.GET_POS_X(), 1);
+
+    let point = point.build();
+    assert_eq!((point.pos_x, point.pos_y), (1, Some(2)));
+}
@@ -0,0 +1,9 @@
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(rename_all = "kebab-case")]
+pub struct Command {
+    executable: String,
+}
+
+fn main() {}
@@ -0,0 +1,82 @@
+// The values of the fields whose setter was not called can be taken from a
+// template instance instead of `Default::default()`.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(default_from = "DEFAULT_SERVER")]
+pub struct Server {
+    host: String,
+    port: u16,
+    workers: Option<u32>,
+    #[builder(default = "Some(30)")]
+    timeout: Option<u32>,
+    #[builder(required)]
+    tls: Option<bool>,
+    #[builder(each = "alias")]
+    aliases: Vec<String>,
+}
+
+const DEFAULT_SERVER: Server = Server {
+    host: String::new(),
+    port: 80,
+    workers: Some(4),
+    timeout: None,
+    tls: None,
+    aliases: Vec::new(),
+};
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(typestate, default_from = "DEFAULT_LIMITS")]
+pub struct Limits {
+    connections: u32,
+    requests: u32,
+}
+
+static DEFAULT_LIMITS: Limits = Limits {
+    connections: 100,
+    requests: 1000,
+};
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(const, default_from = "Point::ORIGIN")]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    const ORIGIN: Point = Point { x: 0, y: 0 };
+}
+
+const UNIT_X: Point = Point::builder().x(1).build();
+
+fn main() {
+    let server = Server::builder()
+        .host("localhost".to_owned())
+        .tls(Some(true))
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        server,
+        Server {
+            host: "localhost".to_owned(),
+            port: 80,
+            workers: Some(4),
+            timeout: Some(30),
+            tls: Some(true),
+            aliases: Vec::new(),
+        }
+    );
+
+    assert_eq!(
+        Server::builder().build().unwrap_err().msg(),
+        "field `tls` was not set"
+    );
+
+    let limits = Limits::builder().requests(10).build();
+    assert_eq!(limits, Limits { connections: 100, requests: 10 });
+
+    assert_eq!(UNIT_X, Point { x: 1, y: 0 });
+}
@@ -0,0 +1,112 @@
+// A builder can also be used as a patch: apply() only overwrites the fields
+// of an existing value whose setters were called.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, PartialEq)]
+pub struct Limits {
+    connections: Option<u32>,
+    requests: Option<u32>,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(finalize = "count_aliases")]
+pub struct Server {
+    host: String,
+    port: u16,
+    workers: Option<u32>,
+    #[builder(each = "alias")]
+    aliases: Vec<String>,
+    #[builder(sub_builder)]
+    limits: Limits,
+    #[builder(computed)]
+    alias_count: usize,
+}
+
+#[derive(Builder, Debug)]
+#[builder(build_fn(by_ref))]
+pub struct Template {
+    name: String,
+    size: u32,
+}
+
+fn count_aliases(server: &mut Server) {
+    server.alias_count = server.aliases.len();
+}
+
+fn main() {
+    let mut server = Server {
+        host: "localhost".to_owned(),
+        port: 80,
+        workers: Some(4),
+        aliases: vec!["local".to_owned()],
+        limits: Limits {
+            connections: Some(100),
+            requests: Some(1000),
+        },
+        alias_count: 1,
+    };
+
+    let mut patch = Server::builder();
+    patch.port(8080).workers(8);
+    patch.limits_builder().requests(10);
+    patch.apply(&mut server);
+
+    assert_eq!(
+        server,
+        Server {
+            host: "localhost".to_owned(),
+            port: 8080,
+            workers: Some(8),
+            aliases: vec!["local".to_owned()],
+            limits: Limits {
+                connections: Some(100),
+                requests: Some(10),
+            },
+            alias_count: 1,
+        }
+    );
+
+    let mut patch = Server::builder();
+    patch.alias("home".to_owned());
+    patch.apply(&mut server);
+
+    assert_eq!(server.aliases, ["home"]);
+    assert_eq!(server.port, 8080);
+
+    Server::builder().apply(&mut server);
+    assert_eq!(server.host, "localhost");
+
+    // The setters return `&mut Self`, so apply() can end the chain.
+    Server::builder().host("example.com".to_owned()).apply(&mut server);
+    assert_eq!(server.host, "example.com");
+
+    let mut limits = Limits {
+        connections: None,
+        requests: None,
+    };
+    let mut patch = Limits::builder();
+    patch.connections(5).apply(&mut limits);
+    patch.apply(&mut limits);
+
+    assert_eq!(limits.connections, Some(5));
+
+    // With `by_ref`, the builder keeps its values like it does for build().
+    let mut template = Template::builder();
+    template.name("base".to_owned());
+
+    let mut first = Template {
+        name: String::new(),
+        size: 1,
+    };
+    let mut second = Template {
+        name: String::new(),
+        size: 2,
+    };
+    template.apply(&mut first);
+    template.apply(&mut second);
+
+    assert_eq!(first.name, "base");
+    assert_eq!(second.name, "base");
+    assert_eq!(second.size, 2);
+}
@@ -0,0 +1,21 @@
+// The apply() method is not generated if the builder already has a method
+// named `apply`, so a field named `apply` keeps its setter.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug)]
+pub struct Patch {
+    apply: bool,
+    target: String,
+}
+
+fn main() {
+    let patch = Patch::builder()
+        .apply(true)
+        .target("config".to_owned())
+        .build()
+        .unwrap();
+
+    assert!(patch.apply);
+    assert_eq!(patch.target, "config");
+}
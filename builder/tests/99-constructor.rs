@@ -0,0 +1,82 @@
+// With `constructor`, a `new()` function taking the required fields is
+// generated next to the builder for callers that need nothing else.
+
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(constructor)]
+pub struct Command {
+    executable: String,
+    #[builder(setter(into))]
+    name: String,
+    current_dir: Option<String>,
+    #[builder(default = "1")]
+    jobs: u32,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(required)]
+    timeout: Option<u32>,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(constructor, typestate, setter(prefix = "with_"))]
+pub struct Point(i32, i32, Option<i32>);
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(constructor, const, setter(owned))]
+pub struct Size {
+    width: u32,
+    height: Option<u32>,
+}
+
+const SQUARE: Size = Size::new(2);
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(constructor)]
+pub struct Flags {
+    verbose: Option<bool>,
+}
+
+#[derive(Builder, Debug, PartialEq)]
+#[builder(constructor, finalize = "count_args")]
+pub struct Invocation {
+    program: String,
+    #[builder(field(ty = "Vec<String>", build = "self.args.into_boxed_slice()"))]
+    args: Box<[String]>,
+    #[builder(default)]
+    retries: u32,
+    #[builder(computed)]
+    arg_count: usize,
+}
+
+fn count_args(invocation: &mut Invocation) {
+    invocation.arg_count = invocation.args.len() + 1;
+}
+
+fn main() {
+    assert_eq!(
+        Command::new("cargo".to_owned(), "build".to_owned(), None),
+        Command {
+            executable: "cargo".to_owned(),
+            name: "build".to_owned(),
+            current_dir: None,
+            jobs: 1,
+            args: Vec::new(),
+            timeout: None,
+        }
+    );
+
+    assert_eq!(Point::new(1, 2), Point(1, 2, None));
+    assert_eq!(SQUARE, Size { width: 2, height: None });
+    assert_eq!(Flags::new(), Flags { verbose: None });
+
+    assert_eq!(
+        Invocation::new("cargo".to_owned()),
+        Invocation {
+            program: "cargo".to_owned(),
+            args: Box::new([]),
+            retries: 0,
+            arg_count: 1,
+        }
+    );
+}
@@ -13,8 +13,8 @@ fn tests() {
 
     t.compile_fail("tests/10-generic-struct.rs");
     t.pass("tests/11-unit-struct.rs");
-    t.compile_fail("tests/12-tuple-struct.rs");
-    t.compile_fail("tests/13-enum.rs");
+    t.pass("tests/12-tuple-struct.rs");
+    t.pass("tests/13-enum.rs");
     t.compile_fail("tests/14-union.rs");
     t.compile_fail("tests/15-name-value-attribute.rs");
     t.compile_fail("tests/16-path-attribute.rs");
@@ -28,4 +28,83 @@ fn tests() {
     t.pass("tests/24-all-option-paths-in-optional-fields.rs");
     t.pass("tests/25-option-lookalike-fields.rs");
     t.pass("tests/26-generated-methods-can-be-accessed-if-struct-is-pub.rs");
+    t.pass("tests/27-raw-identifier-fields.rs");
+    t.pass("tests/28-computed-fields.rs");
+    t.compile_fail("tests/29-computed-field-without-finalize.rs");
+    t.pass("tests/30-enum-keyword-variants.rs");
+    t.pass("tests/31-default-fields.rs");
+    t.compile_fail("tests/32-default-repeated-field.rs");
+    t.pass("tests/33-default-expression-fields.rs");
+    t.compile_fail("tests/34-default-expression-type-mismatch.rs");
+    t.pass("tests/35-setter-into.rs");
+    t.pass("tests/36-setter-strip-option.rs");
+    t.compile_fail("tests/37-setter-strip-option-non-option-field.rs");
+    t.pass("tests/38-setter-try-into.rs");
+    t.pass("tests/39-typestate.rs");
+    t.compile_fail("tests/40-typestate-missing-field.rs");
+    t.pass("tests/41-typestate-field-name-collisions.rs");
+    t.pass("tests/42-build-fn-name.rs");
+    t.pass("tests/43-build-fn-error.rs");
+    t.compile_fail("tests/44-build-fn-error-without-from-impl.rs");
+    t.pass("tests/45-build-fn-validate.rs");
+    t.pass("tests/46-infallible-build.rs");
+    t.pass("tests/47-builder-name.rs");
+    t.pass("tests/48-builder-vis.rs");
+    t.compile_fail("tests/49-builder-vis-private.rs");
+    t.pass("tests/50-builder-derive.rs");
+    t.pass("tests/51-skip-fields.rs");
+    t.compile_fail("tests/52-skip-and-computed-field.rs");
+    t.pass("tests/53-setter-prefix.rs");
+    t.compile_fail("tests/54-setter-prefix-not-an-identifier.rs");
+    t.pass("tests/55-empty-setter-prefix.rs");
+    t.pass("tests/56-repeated-map-field.rs");
+    t.pass("tests/57-repeated-field-extend.rs");
+    t.pass("tests/58-setter-append-only.rs");
+    t.compile_fail("tests/59-setter-append-only-without-each.rs");
+    t.pass("tests/60-setter-owned.rs");
+    t.compile_fail("tests/61-setter-mutable-typestate.rs");
+    t.pass("tests/62-build-fn-by-ref.rs");
+    t.pass("tests/63-builder-default.rs");
+    t.compile_fail("tests/64-builder-derive-default.rs");
+    t.pass("tests/65-to-builder.rs");
+    t.pass("tests/66-serde.rs");
+    t.pass("tests/67-env-fallback.rs");
+    t.compile_fail("tests/68-env-repeated-field.rs");
+    t.pass("tests/69-forward-field-docs.rs");
+    t.pass("tests/70-getters.rs");
+    t.pass("tests/71-required-option-field.rs");
+    t.compile_fail("tests/72-required-strip-option.rs");
+    t.pass("tests/73-sub-builder.rs");
+    t.compile_fail("tests/74-sub-builder-typestate.rs");
+    t.pass("tests/75-mutators.rs");
+    t.compile_fail("tests/76-mutators-on-enum.rs");
+    t.pass("tests/77-custom-field-type.rs");
+    t.compile_fail("tests/78-custom-field-type-without-build.rs");
+    t.pass("tests/79-error-field.rs");
+    t.pass("tests/80-error-field-name-collisions.rs");
+    t.pass("tests/81-aggregate-missing-fields.rs");
+    t.pass("tests/82-no-std.rs");
+    t.compile_fail("tests/83-no-std-env.rs");
+    t.pass("tests/84-cfg-fields.rs");
+    t.pass("tests/85-setter-name.rs");
+    t.pass("tests/86-maybe-setter.rs");
+    t.pass("tests/87-const-builder.rs");
+    t.compile_fail("tests/88-const-builder-missing-field.rs");
+    t.compile_fail("tests/89-const-builder-repeated-field.rs");
+    t.pass("tests/90-build-fn-wrap.rs");
+    t.pass("tests/91-clear-fields.rs");
+    t.pass("tests/92-repeated-field-try-into.rs");
+    t.pass("tests/93-phantom-data-fields.rs");
+    t.pass("tests/94-rename-all.rs");
+    t.compile_fail("tests/95-rename-all-unknown-convention.rs");
+    t.pass("tests/96-default-from.rs");
+    t.pass("tests/97-apply.rs");
+    t.pass("tests/98-apply-field.rs");
+    t.pass("tests/99-constructor.rs");
+    t.compile_fail("tests/100-constructor-with-validate.rs");
+    t.pass("tests/101-buildable.rs");
+    t.compile_fail("tests/102-multiple-attribute-errors.rs");
+    t.compile_fail("tests/103-unrecognized-key-suggestions.rs");
+    t.pass("tests/104-remote.rs");
+    t.compile_fail("tests/105-remote-field-type-mismatch.rs");
 }